@@ -0,0 +1,174 @@
+//! Generates one test per confirmed answer in the `answers/yYYYY.toml`
+//! ledgers. The tests are included by `tests/verify_test.rs`, and ignored
+//! when the real input is not available, like in checkouts where `inputs/`
+//! is still encrypted.
+//!
+//! The answers of the examples, in `answers/examples/yYYYY.toml`, get one
+//! test each too. The examples are never encrypted, so these tests always
+//! run.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ANSWERS_DIR: &str = "answers";
+
+const EXAMPLES_DIR: &str = "answers/examples";
+
+const INPUTS_DIR: &str = "inputs";
+
+/// Start of the files encrypted by git-crypt
+const GIT_CRYPT_HEADER: &[u8] = b"\0GITCRYPT";
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    // Watching a missing path would rerun the script on every build
+    if Path::new(ANSWERS_DIR).is_dir() {
+        println!("cargo::rerun-if-changed={}", ANSWERS_DIR);
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut tests = generate_tests(Path::new(ANSWERS_DIR));
+    tests.push_str(&generate_example_tests(Path::new(EXAMPLES_DIR)));
+
+    fs::write(out_dir.join("verify_tests.rs"), tests).unwrap();
+}
+
+/// Renders a test calling `verify` for every confirmed answer found in the
+/// ledgers of `answers_dir`
+fn generate_tests(answers_dir: &Path) -> String {
+    let mut tests = String::new();
+    for (year, ledger) in yearly_tables(answers_dir) {
+        for (key, record) in &ledger {
+            let Some(day) = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            for part in ["p1", "p2"] {
+                let confirmed = record
+                    .get(part)
+                    .and_then(|part| part.get("confirmed"))
+                    .and_then(|confirmed| confirmed.as_str());
+
+                if let Some(confirmed) = confirmed {
+                    tests.push_str(&ignore_attribute(year, day));
+                    tests.push_str(&format!(
+                        "#[test]\nfn y{year}_day{day:02}_{part}() {{\n    \
+                         verify({year}, {day}, Part::{}, {confirmed:?});\n}}\n\n",
+                        part.to_uppercase()
+                    ));
+                }
+            }
+        }
+    }
+
+    tests
+}
+
+/// Renders an `#[ignore]` attribute when the input of the day is missing or
+/// still encrypted, so the test is reported as skipped instead of passing
+fn ignore_attribute(year: u32, day: u32) -> String {
+    let path = Path::new(INPUTS_DIR)
+        .join(format!("y{}", year))
+        .join(format!("day{:02}.txt", day));
+
+    match fs::read(&path) {
+        Ok(bytes) if !bytes.starts_with(GIT_CRYPT_HEADER) => {
+            println!("cargo::rerun-if-changed={}", path.display());
+            String::new()
+        }
+        Ok(_) => {
+            println!("cargo::rerun-if-changed={}", path.display());
+            format!("#[ignore = \"{} is encrypted\"]\n", path.display())
+        }
+        Err(_) => format!("#[ignore = \"{} is missing\"]\n", path.display()),
+    }
+}
+
+/// Renders a test calling `verify_example` for every example answer found
+/// in the files of `examples_dir`, which look like:
+///
+/// ```toml
+/// [day01]
+/// p1 = { example = 1, answer = "142" }
+/// ```
+fn generate_example_tests(examples_dir: &Path) -> String {
+    let mut tests = String::new();
+    for (year, answers) in yearly_tables(examples_dir) {
+        for (key, record) in &answers {
+            let Some(day) = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+            else {
+                continue;
+            };
+
+            for part in ["p1", "p2"] {
+                let Some(record) = record.get(part) else {
+                    continue;
+                };
+                let example = record
+                    .get("example")
+                    .and_then(|example| example.as_integer())
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "y{year}.toml: day{day:02}.{part} has no example"
+                        )
+                    });
+                let answer = record
+                    .get("answer")
+                    .and_then(|answer| answer.as_str())
+                    .unwrap_or_else(|| {
+                        panic!("y{year}.toml: day{day:02}.{part} has no answer")
+                    });
+
+                tests.push_str(&format!(
+                    "#[test]\nfn example_y{year}_day{day:02}_{part}() {{\n    \
+                     verify_example({year}, {day}, Part::{}, {example}, \
+                     {answer:?});\n}}\n\n",
+                    part.to_uppercase()
+                ));
+            }
+        }
+    }
+
+    tests
+}
+
+/// Returns the `yYYYY.toml` files of `dir`, parsed and sorted by year
+fn yearly_tables(dir: &Path) -> Vec<(u32, toml::Table)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<(u32, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let year = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix('y')?
+                .strip_suffix(".toml")?
+                .parse()
+                .ok()?;
+
+            Some((year, path))
+        })
+        .collect();
+    files.sort();
+
+    files
+        .into_iter()
+        .map(|(year, path)| {
+            let content = fs::read_to_string(&path).unwrap();
+            let table = content
+                .parse()
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+            (year, table)
+        })
+        .collect()
+}
@@ -0,0 +1,425 @@
+//! Ledger of the answers sent to AoC, one file per year.
+//!
+//! It keeps the confirmed answer of every part, and the wrong guesses with
+//! the hints AoC gave about them, so they are not sent twice.
+
+use crate::services::http::SubmitOutcome;
+use crate::solvers::Solution;
+use crate::Part;
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Returns the path of the ledger of the given year:
+/// `<answers_dir>/yYYYY.toml`
+pub fn ledger_path(answers_dir: &Path, year: u32) -> PathBuf {
+    answers_dir.join(format!("y{}.toml", year))
+}
+
+/// Everything known about the answers of a part
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct PartRecord {
+    /// The answer AoC accepted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirmed: Option<String>,
+
+    /// Rejected answers without a hint
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+
+    /// Rejected answers AoC reported as too high
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<String>,
+
+    /// Rejected answers AoC reported as too low
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<String>,
+}
+
+impl PartRecord {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the lowest answer known to be too high
+    fn upper_bound(&self) -> Option<i128> {
+        self.too_high.iter().filter_map(|x| x.parse().ok()).min()
+    }
+
+    /// Returns the highest answer known to be too low
+    fn lower_bound(&self) -> Option<i128> {
+        self.too_low.iter().filter_map(|x| x.parse().ok()).max()
+    }
+
+    fn is_known_wrong(&self, answer: &str) -> bool {
+        [&self.wrong, &self.too_high, &self.too_low]
+            .iter()
+            .any(|answers| answers.iter().any(|x| x == answer))
+    }
+}
+
+/// Answers of both parts of a day
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct DayRecord {
+    #[serde(default, skip_serializing_if = "PartRecord::is_empty")]
+    pub p1: PartRecord,
+
+    #[serde(default, skip_serializing_if = "PartRecord::is_empty")]
+    pub p2: PartRecord,
+}
+
+/// Why an answer should not be sent to AoC
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum Rejection {
+    #[display("{_0} was already accepted as the right answer")]
+    AlreadyConfirmed(String),
+
+    #[display("this answer was already rejected")]
+    KnownWrong,
+
+    #[display("this answer is too high, it should be lower than {_0}")]
+    TooHigh(i128),
+
+    #[display("this answer is too low, it should be higher than {_0}")]
+    TooLow(i128),
+}
+
+/// How a solution compares with the confirmed answer of its part
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    /// The solution is the confirmed answer
+    Confirmed,
+    /// The solution differs from the confirmed answer, or it is a known
+    /// wrong answer
+    Regression,
+    /// There is no confirmed answer to compare with
+    Unknown,
+}
+
+/// The answers of every day of a year, as stored in `yYYYY.toml`
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct Ledger {
+    days: BTreeMap<String, DayRecord>,
+}
+
+impl Ledger {
+    /// Returns what is known about the given part
+    pub fn part(&self, day: u32, part: Part) -> Option<&PartRecord> {
+        let record = self.days.get(&day_key(day))?;
+
+        Some(match part {
+            Part::P1 => &record.p1,
+            Part::P2 => &record.p2,
+        })
+    }
+
+    fn part_mut(&mut self, day: u32, part: Part) -> &mut PartRecord {
+        let record = self.days.entry(day_key(day)).or_default();
+
+        match part {
+            Part::P1 => &mut record.p1,
+            Part::P2 => &mut record.p2,
+        }
+    }
+
+    /// Returns why the answer should not be submitted, if it is known it
+    /// would be rejected
+    pub fn check(
+        &self,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> Option<Rejection> {
+        let record = self.part(day, part)?;
+
+        if let Some(confirmed) = &record.confirmed {
+            return Some(Rejection::AlreadyConfirmed(confirmed.clone()));
+        }
+
+        if record.is_known_wrong(answer) {
+            return Some(Rejection::KnownWrong);
+        }
+
+        let answer: i128 = answer.parse().ok()?;
+
+        if let Some(bound) = record.upper_bound().filter(|b| answer >= *b) {
+            return Some(Rejection::TooHigh(bound));
+        }
+
+        if let Some(bound) = record.lower_bound().filter(|b| answer <= *b) {
+            return Some(Rejection::TooLow(bound));
+        }
+
+        None
+    }
+
+    /// Records what AoC said about a submitted answer. Returns whether
+    /// the ledger changed.
+    pub fn record(
+        &mut self,
+        day: u32,
+        part: Part,
+        answer: &str,
+        outcome: SubmitOutcome,
+    ) -> bool {
+        if matches!(
+            outcome,
+            SubmitOutcome::RateLimited(_) | SubmitOutcome::WrongLevel
+        ) {
+            return false;
+        }
+
+        let record = self.part_mut(day, part);
+        let answers = match outcome {
+            SubmitOutcome::Right => {
+                record.confirmed = Some(answer.to_string());
+                return true;
+            }
+            SubmitOutcome::Wrong => &mut record.wrong,
+            SubmitOutcome::TooHigh => &mut record.too_high,
+            SubmitOutcome::TooLow => &mut record.too_low,
+            _ => unreachable!("outcomes without information return early"),
+        };
+
+        if answers.iter().any(|x| x == answer) {
+            return false;
+        }
+        answers.push(answer.to_string());
+
+        true
+    }
+
+    /// Compares the solution with the confirmed answer of its part.
+    /// Solutions that did not run get no verdict.
+    pub fn verdict(
+        &self,
+        day: u32,
+        part: Part,
+        solution: &Solution,
+    ) -> Option<Verdict> {
+        let record = self.part(day, part);
+        let confirmed = record.and_then(|record| record.confirmed.as_ref());
+
+        match (solution, confirmed) {
+            (
+                Solution::NotExecuted
+                | Solution::NotImplemented
+                | Solution::SinglePart,
+                _,
+            ) => None,
+            (Solution::Value(answer), Some(confirmed)) => {
+                match answer.to_string() == *confirmed {
+                    true => Some(Verdict::Confirmed),
+                    false => Some(Verdict::Regression),
+                }
+            }
+            (Solution::Err(_), Some(_)) => Some(Verdict::Regression),
+            (Solution::Value(answer), None) => {
+                match record
+                    .is_some_and(|r| r.is_known_wrong(&answer.to_string()))
+                {
+                    true => Some(Verdict::Regression),
+                    false => Some(Verdict::Unknown),
+                }
+            }
+            (Solution::Err(_), None) => Some(Verdict::Unknown),
+        }
+    }
+
+    /// Returns the verdicts of both parts of the given day
+    pub fn verdicts(
+        &self,
+        day: u32,
+        solutions: &[Solution; 2],
+    ) -> [Option<Verdict>; 2] {
+        [
+            self.verdict(day, Part::P1, &solutions[0]),
+            self.verdict(day, Part::P2, &solutions[1]),
+        ]
+    }
+}
+
+fn day_key(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::{Answer, SolverError};
+
+    const LEDGER: &str = r#"[day01.p1]
+confirmed = "1234"
+
+[day02.p2]
+wrong = ["7"]
+too_high = ["500", "300"]
+too_low = ["100"]
+"#;
+
+    fn ledger() -> Ledger {
+        toml::from_str(LEDGER).unwrap()
+    }
+
+    #[test]
+    fn test_ledger_path() {
+        let expected: PathBuf = ["answers", "y2024.toml"].iter().collect();
+
+        assert_eq!(ledger_path(Path::new("answers"), 2024), expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(toml::to_string(&ledger()).unwrap(), LEDGER);
+    }
+
+    mod check {
+        use super::*;
+
+        #[test]
+        fn test_already_confirmed() {
+            assert_eq!(
+                ledger().check(1, Part::P1, "1"),
+                Some(Rejection::AlreadyConfirmed("1234".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_known_wrong() {
+            assert_eq!(
+                ledger().check(2, Part::P2, "7"),
+                Some(Rejection::KnownWrong)
+            );
+            assert_eq!(
+                ledger().check(2, Part::P2, "500"),
+                Some(Rejection::KnownWrong)
+            );
+        }
+
+        #[test]
+        fn test_out_of_bounds() {
+            assert_eq!(
+                ledger().check(2, Part::P2, "301"),
+                Some(Rejection::TooHigh(300))
+            );
+            assert_eq!(
+                ledger().check(2, Part::P2, "99"),
+                Some(Rejection::TooLow(100))
+            );
+        }
+
+        #[test]
+        fn test_unknown_answers_are_accepted() {
+            assert_eq!(ledger().check(2, Part::P2, "200"), None);
+            assert_eq!(ledger().check(2, Part::P2, "abc"), None);
+            assert_eq!(ledger().check(1, Part::P2, "1"), None);
+            assert_eq!(ledger().check(3, Part::P1, "1"), None);
+        }
+    }
+
+    mod record {
+        use super::*;
+        use std::time::Duration;
+
+        #[test]
+        fn test_right_answer() {
+            let mut ledger = Ledger::default();
+
+            assert!(ledger.record(3, Part::P1, "42", SubmitOutcome::Right));
+            assert_eq!(
+                ledger.part(3, Part::P1).unwrap().confirmed,
+                Some("42".to_string())
+            );
+        }
+
+        #[test]
+        fn test_wrong_answers_with_hints() {
+            let mut ledger = Ledger::default();
+
+            assert!(ledger.record(3, Part::P2, "1", SubmitOutcome::Wrong));
+            assert!(ledger.record(3, Part::P2, "9", SubmitOutcome::TooHigh));
+            assert!(ledger.record(3, Part::P2, "2", SubmitOutcome::TooLow));
+            assert!(!ledger.record(3, Part::P2, "9", SubmitOutcome::TooHigh));
+
+            let record = ledger.part(3, Part::P2).unwrap();
+            assert_eq!(record.wrong, ["1"]);
+            assert_eq!(record.too_high, ["9"]);
+            assert_eq!(record.too_low, ["2"]);
+        }
+
+        #[test]
+        fn test_outcomes_without_information() {
+            let mut ledger = Ledger::default();
+            let rate_limited = SubmitOutcome::RateLimited(Duration::ZERO);
+
+            assert!(!ledger.record(3, Part::P1, "1", rate_limited));
+            assert!(!ledger.record(
+                3,
+                Part::P1,
+                "1",
+                SubmitOutcome::WrongLevel
+            ));
+            assert_eq!(ledger, Ledger::default());
+        }
+    }
+
+    mod verdict {
+        use super::*;
+
+        fn value(x: u64) -> Solution {
+            Solution::Value(Answer::Unsigned(x))
+        }
+
+        #[test]
+        fn test_confirmed() {
+            assert_eq!(
+                ledger().verdict(1, Part::P1, &value(1234)),
+                Some(Verdict::Confirmed)
+            );
+        }
+
+        #[test]
+        fn test_regression() {
+            let error = Solution::Err(SolverError::InvalidInput("x".into()));
+
+            assert_eq!(
+                ledger().verdict(1, Part::P1, &value(1)),
+                Some(Verdict::Regression)
+            );
+            assert_eq!(
+                ledger().verdict(1, Part::P1, &error),
+                Some(Verdict::Regression)
+            );
+            assert_eq!(
+                ledger().verdict(2, Part::P2, &value(7)),
+                Some(Verdict::Regression)
+            );
+        }
+
+        #[test]
+        fn test_unknown() {
+            assert_eq!(
+                ledger().verdict(2, Part::P2, &value(200)),
+                Some(Verdict::Unknown)
+            );
+            assert_eq!(
+                ledger().verdict(9, Part::P1, &value(1)),
+                Some(Verdict::Unknown)
+            );
+        }
+
+        #[test]
+        fn test_not_run() {
+            assert_eq!(
+                ledger().verdicts(
+                    1,
+                    &[Solution::NotExecuted, Solution::NotImplemented]
+                ),
+                [None, None]
+            );
+        }
+    }
+}
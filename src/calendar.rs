@@ -0,0 +1,71 @@
+//! Calendar of the AoC events.
+//!
+//! There is an event every December since 2015. Until 2024 it had 25
+//! puzzles, and from 2025 on it has 12. The last puzzle of every event only
+//! has one part: its second star is given for completing all the others.
+
+use std::ops::RangeInclusive;
+
+/// The year of the first event
+pub const FIRST_YEAR: u32 = 2015;
+
+/// From this year on, the events only have 12 puzzles
+pub const TWELVE_DAYS_SINCE: u32 = 2025;
+
+/// Returns the years with an event, up to `latest`: the year of the latest
+/// event, as given by the date service
+pub fn years(latest: u32) -> RangeInclusive<u32> {
+    FIRST_YEAR..=latest
+}
+
+/// Returns the days with a puzzle in the event of the given year
+pub fn days(year: u32) -> RangeInclusive<u32> {
+    1..=last_day(year)
+}
+
+/// Returns the day of the last puzzle of the event of the given year
+pub fn last_day(year: u32) -> u32 {
+    match year >= TWELVE_DAYS_SINCE {
+        true => 12,
+        false => 25,
+    }
+}
+
+/// Returns whether the puzzle of the given day only has one part
+pub fn is_single_part(year: u32, day: u32) -> bool {
+    day == last_day(year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_years() {
+        assert_eq!(years(2024), 2015..=2024);
+    }
+
+    #[rstest]
+    #[case(2015, 25)]
+    #[case(2024, 25)]
+    #[case(2025, 12)]
+    #[case(2026, 12)]
+    fn test_last_day(#[case] year: u32, #[case] expected: u32) {
+        assert_eq!(last_day(year), expected);
+        assert_eq!(days(year), 1..=expected);
+    }
+
+    #[rstest]
+    #[case(2024, 24, false)]
+    #[case(2024, 25, true)]
+    #[case(2025, 11, false)]
+    #[case(2025, 12, true)]
+    fn test_is_single_part(
+        #[case] year: u32,
+        #[case] day: u32,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_single_part(year, day), expected);
+    }
+}
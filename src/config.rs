@@ -0,0 +1,57 @@
+//! User configuration, kept out of the repository.
+//!
+//! It lives in `<config dir>/aoc/config.toml` (`~/.config/aoc/config.toml`
+//! on Linux) and stores the AoC session cookie set with `aoc auth set`, so
+//! it is only readable by its owner.
+
+use dirs::config_dir;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Returns the path of the user configuration, if the platform has a
+/// configuration directory
+pub fn default_config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("aoc").join("config.toml"))
+}
+
+/// Settings stored in the user configuration
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The AoC session cookie
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+}
+
+/// Removes the `session=` prefix of a cookie copied from the browser, and
+/// the surrounding whitespace
+pub fn normalize_cookie(cookie: &str) -> String {
+    let cookie = cookie.trim();
+
+    cookie
+        .strip_prefix("session=")
+        .unwrap_or(cookie)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_path() {
+        if let Some(path) = default_config_path() {
+            assert!(path.ends_with("aoc/config.toml"));
+        }
+    }
+
+    #[test]
+    fn test_normalize_cookie() {
+        assert_eq!(normalize_cookie("53616c74\n"), "53616c74");
+        assert_eq!(normalize_cookie(" session=53616c74 "), "53616c74");
+    }
+
+    #[test]
+    fn test_empty_config_is_not_serialized() {
+        assert_eq!(toml::to_string(&Config::default()).unwrap(), "");
+    }
+}
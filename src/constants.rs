@@ -1,12 +1,51 @@
 //! Constants for AoC project
 
 use std::ops::RangeInclusive;
+use std::time::Duration;
 
-/// Years when I have solutions for the AoC games
-pub const VALID_YEARS: RangeInclusive<u32> = 2023..=2024;
-
-/// December days that contain a puzzle
-pub const VALID_DAYS: RangeInclusive<u32> = 1..=25;
+/// Optional envvar with the timezone of the default year and day, as an
+/// IANA name such as `Europe/Madrid`. AoC's own timezone is used otherwise.
+pub const AOC_TIMEZONE: &str = "AOC_TIMEZONE";
 
 /// The require AOC_COOKIE
 pub const AOC_COOKIE: &str = "AOC_COOKIE";
+
+/// Optional envvar with the path of the user configuration
+pub const AOC_CONFIG: &str = "AOC_CONFIG";
+
+/// Optional envvar with the directory where the puzzle inputs are cached
+pub const AOC_INPUTS_DIR: &str = "AOC_INPUTS_DIR";
+
+/// Directory where the puzzle inputs are cached, unless configured otherwise
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// Optional envvar with the directory where the answers ledgers are kept
+pub const AOC_ANSWERS_DIR: &str = "AOC_ANSWERS_DIR";
+
+/// Directory where the answers ledgers are kept, unless configured otherwise
+pub const DEFAULT_ANSWERS_DIR: &str = "answers";
+
+/// Offset of the time zone where AoC unlocks the puzzles at midnight:
+/// UTC-5, the US-Eastern standard time
+pub const UNLOCK_UTC_OFFSET_HOURS: i32 = -5;
+
+/// Bounds of the random delay, in milliseconds, before downloading a puzzle
+/// that just unlocked, so not every tool hits AoC at the same instant
+pub const UNLOCK_DELAY_MS: RangeInclusive<u64> = 500..=3000;
+
+/// AoC asks not to request a private leaderboard more often than this
+pub const LEADERBOARD_CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Optional envvar with the User-Agent sent to AoC
+pub const AOC_USER_AGENT: &str = "AOC_USER_AGENT";
+
+/// User-Agent sent to AoC, unless configured otherwise. AoC asks automated
+/// tools to identify themselves and give a way to contact their author.
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
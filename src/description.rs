@@ -0,0 +1,458 @@
+//! Description of a puzzle, as published in its page.
+//!
+//! Every part is an `<article class="day-desc">`. They are rendered as
+//! plain text for the terminal, or as Markdown, and their code blocks are
+//! the examples of the puzzle. The answer of an example is the last
+//! emphasized code (`<code><em>42</em></code>`) following its block.
+
+use crate::Part;
+use clap::ValueEnum;
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Controls how the description is rendered
+#[derive(PartialEq, Eq, Clone, Copy, Debug, ValueEnum, Default)]
+pub enum DescriptionFormat {
+    #[default]
+    Text,
+    Markdown,
+}
+
+/// An example of the puzzle, with the answers the description gives for it
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Example {
+    pub input: String,
+    /// Expected answers of part 1 and part 2
+    pub answers: [Option<String>; 2],
+}
+
+/// The articles describing each unlocked part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Description {
+    articles: Vec<String>,
+}
+
+impl Description {
+    /// Extracts the description from a puzzle page, or from a previously
+    /// cached description
+    pub fn from_html(html: &str) -> Self {
+        let selector = Selector::parse("article.day-desc").unwrap();
+        let document = Html::parse_document(html);
+
+        Self {
+            articles: document
+                .select(&selector)
+                .map(|article| article.html())
+                .collect(),
+        }
+    }
+
+    /// Returns the HTML of the articles, as it is cached
+    pub fn to_html(&self) -> String {
+        self.articles.join("\n")
+    }
+
+    /// Returns whether there is nothing to show, because the page had no
+    /// description
+    pub fn is_empty(&self) -> bool {
+        self.articles.is_empty()
+    }
+
+    /// Returns whether the description of the given part is unlocked
+    pub fn has(&self, part: Part) -> bool {
+        self.articles.len() >= part.level() as usize
+    }
+
+    /// Renders the description of the given part, or of every unlocked
+    /// part. Returns `None` when the part is not unlocked.
+    pub fn render(
+        &self,
+        part: Option<Part>,
+        format: DescriptionFormat,
+    ) -> Option<String> {
+        let articles = match part {
+            Some(part) => {
+                let index = part.level() as usize - 1;
+                self.articles.get(index..=index)?
+            }
+            None => &self.articles[..],
+        };
+
+        let rendered: Vec<String> = articles
+            .iter()
+            .map(|article| render_article(article, format))
+            .collect();
+
+        Some(rendered.join("\n\n") + "\n")
+    }
+
+    /// Returns every code block in order of appearance, with the answers
+    /// found after it. Blocks repeated in part 2 are returned once, and the
+    /// answers given in part 2 before any block of its own belong to the
+    /// last example of part 1.
+    pub fn examples(&self) -> Vec<Example> {
+        let mut examples: Vec<Example> = Vec::new();
+
+        for (part, article) in self.articles.iter().take(2).enumerate() {
+            let fragment = Html::parse_fragment(article);
+            let mut current = examples.len().checked_sub(1);
+
+            for node in fragment.root_element().descendants() {
+                let Some(element) = ElementRef::wrap(node) else {
+                    continue;
+                };
+
+                if element.value().name() == "pre" {
+                    let input: String = element.text().collect();
+                    let index = examples
+                        .iter()
+                        .position(|example| example.input == input)
+                        .unwrap_or_else(|| {
+                            examples.push(Example {
+                                input,
+                                ..Default::default()
+                            });
+                            examples.len() - 1
+                        });
+                    current = Some(index);
+                } else if is_answer(element) {
+                    if let Some(index) = current {
+                        examples[index].answers[part] =
+                            Some(element.text().collect());
+                    }
+                }
+            }
+        }
+
+        examples
+    }
+}
+
+/// Returns whether the element is an emphasized code outside of the code
+/// blocks, the way answers are highlighted
+fn is_answer(element: ElementRef) -> bool {
+    if element.value().name() != "code" {
+        return false;
+    }
+
+    let has_name = |node: ego_tree::NodeRef<Node>, name: &str| {
+        node.value().as_element().is_some_and(|e| e.name() == name)
+    };
+    let emphasized = element.children().any(|child| has_name(child, "em"))
+        || element
+            .parent()
+            .is_some_and(|parent| has_name(parent, "em"));
+
+    emphasized && !element.ancestors().any(|node| has_name(node, "pre"))
+}
+
+/// Renders the blocks of an article, separated by blank lines
+fn render_article(html: &str, format: DescriptionFormat) -> String {
+    let selector = Selector::parse("article").unwrap();
+    let fragment = Html::parse_fragment(html);
+    let Some(article) = fragment.select(&selector).next() else {
+        return String::new();
+    };
+
+    let mut blocks = Vec::new();
+    for child in article.children() {
+        let Some(element) = ElementRef::wrap(child) else {
+            let text = render_inline_node(child, format);
+            if !text.trim().is_empty() {
+                blocks.push(text.trim().to_string());
+            }
+            continue;
+        };
+
+        let block = match element.value().name() {
+            "h2" => render_heading(element, format),
+            "pre" => render_code_block(element, format),
+            "ul" | "ol" => render_list(element, format),
+            _ => render_inline(element, format).trim().to_string(),
+        };
+
+        blocks.push(block);
+    }
+
+    blocks.join("\n\n")
+}
+
+fn render_heading(element: ElementRef, format: DescriptionFormat) -> String {
+    let title: String = element.text().collect();
+
+    match format {
+        DescriptionFormat::Text => title,
+        DescriptionFormat::Markdown => {
+            let title = title.trim_start_matches("--- ");
+            format!("## {}", title.trim_end_matches(" ---"))
+        }
+    }
+}
+
+fn render_code_block(element: ElementRef, format: DescriptionFormat) -> String {
+    let code: String = element.text().collect();
+    let code = code.trim_end_matches('\n');
+
+    match format {
+        DescriptionFormat::Text => code
+            .lines()
+            .map(|line| format!("    {}", line).trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        DescriptionFormat::Markdown => format!("```\n{}\n```", code),
+    }
+}
+
+fn render_list(element: ElementRef, format: DescriptionFormat) -> String {
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|item| item.value().name() == "li")
+        .map(|item| format!("- {}", render_inline(item, format).trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the content of an element as a single line of text
+fn render_inline(element: ElementRef, format: DescriptionFormat) -> String {
+    element
+        .children()
+        .map(|child| render_inline_node(child, format))
+        .collect()
+}
+
+fn render_inline_node(
+    node: ego_tree::NodeRef<Node>,
+    format: DescriptionFormat,
+) -> String {
+    let Some(element) = ElementRef::wrap(node) else {
+        return match node.value() {
+            Node::Text(text) => match format {
+                DescriptionFormat::Text => text.replace('\n', " "),
+                DescriptionFormat::Markdown => {
+                    escape_markdown(&text.replace('\n', " "))
+                }
+            },
+            _ => String::new(),
+        };
+    };
+
+    let content = render_inline(element, format);
+    if format == DescriptionFormat::Text {
+        return content;
+    }
+
+    match element.value().name() {
+        "em" => format!("**{}**", content),
+        "code" => {
+            let code: String = element.text().collect();
+            let span = match code.contains('`') {
+                true => format!("`` {} ``", code),
+                false => format!("`{}`", code),
+            };
+            let emphasized = element
+                .children()
+                .filter_map(ElementRef::wrap)
+                .any(|child| child.value().name() == "em");
+
+            match emphasized {
+                true => format!("**{}**", span),
+                false => span,
+            }
+        }
+        "a" => match element.value().attr("href") {
+            Some(href) => format!("[{}]({})", content, href),
+            None => content,
+        },
+        _ => content,
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with global snow production.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>In this example, the values are <code>12</code> and <code>38</code>, adding these together produces <code><em>50</em></code>.</p>
+<ul>
+<li>Read the <a href="/2023/about">about page</a>.</li>
+<li>Don't use 2 * 3_4.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>54644</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now with words:</p>
+<pre><code>two1nine
+</code></pre>
+</article>
+</main></body></html>"#;
+
+    const TEXT_P1: &str = "--- Day 1: Trebuchet?! ---
+
+Something is wrong with global snow production.
+
+For example:
+
+    1abc2
+    pqr3stu8vwx
+
+In this example, the values are 12 and 38, adding these together produces 50.
+
+- Read the about page.
+- Don't use 2 * 3_4.
+";
+
+    const MARKDOWN_P1: &str = "## Day 1: Trebuchet?!
+
+Something is **wrong** with global snow production.
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the values are `12` and `38`, adding these together produces **`50`**.
+
+- Read the [about page](/2023/about).
+- Don't use 2 \\* 3\\_4.
+";
+
+    fn description() -> Description {
+        Description::from_html(PAGE)
+    }
+
+    #[test]
+    fn test_articles_are_extracted() {
+        let description = description();
+
+        assert!(description.has(Part::P1));
+        assert!(description.has(Part::P2));
+        assert!(!description.to_html().contains("54644"));
+    }
+
+    #[test]
+    fn test_cached_html_round_trip() {
+        let description = description();
+
+        assert_eq!(Description::from_html(&description.to_html()), description);
+    }
+
+    #[test]
+    fn test_page_without_description() {
+        let description = Description::from_html("<html></html>");
+
+        assert!(description.is_empty());
+        assert!(!description.has(Part::P1));
+        assert_eq!(
+            description.render(Some(Part::P1), Default::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!(
+            description()
+                .render(Some(Part::P1), DescriptionFormat::Text)
+                .unwrap(),
+            TEXT_P1
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(
+            description()
+                .render(Some(Part::P1), DescriptionFormat::Markdown)
+                .unwrap(),
+            MARKDOWN_P1
+        );
+    }
+
+    #[test]
+    fn test_render_every_part() {
+        let rendered =
+            description().render(None, DescriptionFormat::Text).unwrap();
+
+        assert!(rendered.starts_with(TEXT_P1));
+        assert!(rendered.ends_with(
+            "\n--- Part Two ---\n\nNow with words:\n\n    two1nine\n"
+        ));
+    }
+
+    #[test]
+    fn test_locked_part() {
+        let description = Description::from_html(
+            r#"<article class="day-desc"><h2>--- Day 2 ---</h2></article>"#,
+        );
+
+        assert!(!description.has(Part::P2));
+        assert_eq!(
+            description.render(Some(Part::P2), DescriptionFormat::Text),
+            None
+        );
+    }
+
+    fn example(input: &str, answers: [Option<&str>; 2]) -> Example {
+        Example {
+            input: input.to_string(),
+            answers: answers.map(|answer| answer.map(str::to_string)),
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            description().examples(),
+            [
+                example("1abc2\npqr3stu8vwx\n", [Some("50"), None]),
+                example("two1nine\n", [None, None]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_examples_answers() {
+        let description = Description::from_html(
+            r#"<article class="day-desc">
+<pre><code>illustration</code></pre>
+<p>Not an answer: <code>1</code> nor <em>2</em>.</p>
+<pre><code>abc</code></pre>
+<p>It gives <code><em>3</em></code>, then <em><code>4</code></em>.</p>
+</article>
+<article class="day-desc">
+<p>The same example now gives <code><em>5</em></code>.</p>
+<pre><code>illustration</code></pre>
+<pre><code>xyz</code></pre>
+<p>This one gives <code><em>6</em></code>.</p>
+</article>"#,
+        );
+
+        assert_eq!(
+            description.examples(),
+            [
+                example("illustration", [None, None]),
+                example("abc", [Some("4"), Some("5")]),
+                example("xyz", [None, Some("6")]),
+            ]
+        );
+    }
+}
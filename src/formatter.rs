@@ -1,29 +1,72 @@
-use crate::{solvers::Solution, Puzzle};
+use crate::{
+    answers::Verdict,
+    solvers::{
+        Answer, BenchReport, Report, Solution, SolverError, Stats, Timings,
+    },
+    Puzzle,
+};
 use clap::ValueEnum;
 use num_format::{Buffer, Locale};
 use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::time::Duration;
 use tabled::{
     builder::Builder,
     settings::{
-        object::Rows, style::BorderSpanCorrection, Alignment, Settings, Span,
-        Style,
+        object::{Columns, Rows},
+        style::BorderSpanCorrection,
+        Alignment, Settings, Span, Style,
     },
 };
 
-/// Controls whatever to use a table format or a simple text format
+/// Controls whatever to use a table format, a simple text format, or a
+/// format for other programs to read:
+///
+/// * JSON gives one document per puzzle and line, following the schema
+///   [`JSON_SCHEMA_VERSION`]. Their `kind` tells the results of `solve`
+///   from the benchmarks of `bench`.
+/// * Markdown gives a GitHub-flavoured table, to paste in a wiki.
+/// * CSV gives one row per puzzle, with the times in nanoseconds.
+/// * JUnit gives a JUnit XML report for CI, with one test suite per year
+///   and one test case per part.
 #[derive(PartialEq, Eq, Clone, Copy, Debug, ValueEnum, Default)]
 pub enum StyleFormat {
     #[default]
     Simple,
     Tabulated,
+    Json,
+    Markdown,
+    Csv,
+    Junit,
 }
 
+/// Version of the schema of the [`StyleFormat::Json`] documents. It changes
+/// whenever a field is removed or its meaning changes, but not when one is
+/// added.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
 pub struct OutputFormat {
     style: StyleFormat,
     color: bool,
 }
 
+/// The report of a puzzle, with how its solutions compare with the
+/// confirmed answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleResult {
+    pub puzzle: Puzzle,
+    pub report: Report,
+    pub verdicts: [Option<Verdict>; 2],
+}
+
+/// The benchmark of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub puzzle: Puzzle,
+    pub report: BenchReport,
+}
+
 /// Creates a new formatter builder
 pub fn new() -> OutputFormat {
     OutputFormat::default()
@@ -42,17 +85,229 @@ impl OutputFormat {
         self
     }
 
-    /// Formats the given solutions for the given puzzle input
-    pub fn format(&self, puzzle: Puzzle, solutions: &[Solution; 2]) -> String {
+    /// Formats the solutions of the given puzzle, each marked with its
+    /// verdict
+    pub fn format(&self, result: &PuzzleResult) -> String {
+        let PuzzleResult {
+            puzzle,
+            report,
+            verdicts,
+        } = result;
+
+        match self.style {
+            StyleFormat::Simple => print_simple_results(
+                *puzzle,
+                &report.solutions,
+                verdicts,
+                self.color,
+            ),
+            StyleFormat::Tabulated => print_tabulated_results(
+                *puzzle,
+                &report.solutions,
+                verdicts,
+                self.color,
+            ),
+            StyleFormat::Json => print_json_result(result),
+            StyleFormat::Markdown => {
+                print_markdown_table(std::slice::from_ref(result), false)
+            }
+            StyleFormat::Csv => print_csv(std::slice::from_ref(result)),
+            StyleFormat::Junit => print_junit(std::slice::from_ref(result)),
+        }
+    }
+
+    /// Formats the reports of several puzzles together, with the time spent
+    /// on each part and the total time of the whole run. The JSON and CSV
+    /// styles give one line per puzzle, without the total.
+    pub fn format_batch(&self, results: &[PuzzleResult]) -> String {
+        match self.style {
+            StyleFormat::Simple => print_simple_batch(results, self.color),
+            StyleFormat::Tabulated => {
+                print_tabulated_batch(results, self.color)
+            }
+            StyleFormat::Json => print_json_batch(results),
+            StyleFormat::Markdown => print_markdown_table(results, true),
+            StyleFormat::Csv => print_csv(results),
+            StyleFormat::Junit => print_junit(results),
+        }
+    }
+
+    /// Formats the results of a verification run: the results of every
+    /// puzzle, followed by how many parts passed or failed. The JSON, CSV and
+    /// JUnit styles leave the count out, as every part already has its
+    /// verdict.
+    pub fn format_verification(&self, results: &[PuzzleResult]) -> String {
         match self.style {
-            StyleFormat::Simple => {
-                print_simple_results(puzzle, solutions, self.color)
+            StyleFormat::Json | StyleFormat::Csv | StyleFormat::Junit => {
+                self.format_batch(results)
             }
+            StyleFormat::Markdown => format!(
+                "{}\n\n{}",
+                self.format_batch(results),
+                format_summary(results, false)
+            ),
+            _ => format!(
+                "{}\n{}",
+                self.format_batch(results),
+                format_summary(results, self.color)
+            ),
+        }
+    }
+
+    /// Formats the benchmarks of several puzzles, with the timing statistics
+    /// of each step. The JUnit style reports the median time of each part.
+    pub fn format_benchmarks(&self, results: &[BenchResult]) -> String {
+        match self.style {
+            StyleFormat::Simple => print_simple_benchmarks(results, self.color),
             StyleFormat::Tabulated => {
-                print_tabulated_results(puzzle, solutions, self.color)
+                print_tabulated_benchmarks(results, self.color)
             }
+            StyleFormat::Json => print_json_benchmarks(results),
+            StyleFormat::Markdown => print_markdown_benchmarks(results),
+            StyleFormat::Csv => print_csv_benchmarks(results),
+            StyleFormat::Junit => print_junit(
+                &results.iter().map(median_result).collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// Returns whether the output is meant to be pasted or read by other
+    /// programs, so nothing else should be printed along with it
+    pub fn is_machine_readable(&self) -> bool {
+        !matches!(self.style, StyleFormat::Simple | StyleFormat::Tabulated)
+    }
+}
+
+/// Counts the parts that matched their confirmed answer, the ones that did
+/// not, and the ones without a confirmed answer, in that order
+pub fn count_verdicts(results: &[PuzzleResult]) -> [usize; 3] {
+    let mut counts = [0; 3];
+
+    for verdict in results.iter().flat_map(|result| result.verdicts).flatten() {
+        match verdict {
+            Verdict::Confirmed => counts[0] += 1,
+            Verdict::Regression => counts[1] += 1,
+            Verdict::Unknown => counts[2] += 1,
         }
     }
+
+    counts
+}
+
+/// Renders how many parts passed, failed, or could not be checked
+fn format_summary(results: &[PuzzleResult], with_color: bool) -> String {
+    let [passed, failed, unknown] = count_verdicts(results);
+    let mut passed = format!("{} passed", passed);
+    let mut failed = format!("{} failed", failed);
+    if with_color {
+        passed = passed.green().to_string();
+        failed = failed.red().bold().to_string();
+    }
+
+    format!(
+        "Verified: {}, {}, {} without a confirmed answer",
+        passed, failed, unknown
+    )
+}
+
+/// Renders an answer. Numeric answers get thousands separators when
+/// `with_separators` is set; text answers are always kept as they are.
+fn format_answer(answer: &Answer, with_separators: bool) -> String {
+    if !with_separators || !answer.is_numeric() {
+        return answer.to_string();
+    }
+
+    let mut buf = Buffer::default();
+    match answer {
+        Answer::Unsigned(x) => buf.write_formatted(x, &Locale::en),
+        Answer::Signed(x) => buf.write_formatted(x, &Locale::en),
+        Answer::Big(x) => buf.write_formatted(x, &Locale::en),
+        Answer::Text(_) => unreachable!("text answers are not numeric"),
+    };
+
+    buf.to_string()
+}
+
+/// Renders an error after the given prefix. Parse errors also get the
+/// offending line, with a marker under the column where parsing failed.
+fn format_error(err: &SolverError, error_string: &str) -> String {
+    let message = format!("{} {}", error_string, err);
+
+    match err {
+        SolverError::Parse {
+            line,
+            column,
+            context,
+            ..
+        } => {
+            let gutter = " ".repeat(line.to_string().len());
+            let marker = " ".repeat(column.saturating_sub(1));
+
+            [
+                message,
+                format!("{} | {}", line, context),
+                format!("{} | {}^", gutter, marker),
+            ]
+            .join("\n")
+        }
+        _ => message,
+    }
+}
+
+/// Renders the mark of a verdict: ✔ confirmed, ✘ regression, ? unknown
+fn format_verdict(verdict: Verdict, with_color: bool) -> String {
+    match (verdict, with_color) {
+        (Verdict::Confirmed, true) => "✔".green().to_string(),
+        (Verdict::Confirmed, false) => "✔".to_string(),
+        (Verdict::Regression, true) => "✘".red().bold().to_string(),
+        (Verdict::Regression, false) => "✘".to_string(),
+        (Verdict::Unknown, true) => "?".yellow().to_string(),
+        (Verdict::Unknown, false) => "?".to_string(),
+    }
+}
+
+/// Renders a single solution, using `error_string` as the prefix of errors.
+/// The verdict mark, if any, goes after it.
+fn format_solution(
+    solution: &Solution,
+    verdict: Option<Verdict>,
+    error_string: &str,
+    with_color: bool,
+    with_separators: bool,
+) -> String {
+    let solution = match solution {
+        Solution::NotExecuted => "Not executed".to_string(),
+        Solution::NotImplemented => "Not implemented".to_string(),
+        Solution::SinglePart => "No part 2".to_string(),
+        Solution::Err(err) => format_error(err, error_string),
+        Solution::Value(x) => match with_color {
+            true => format_answer(x, with_separators).green().to_string(),
+            false => format_answer(x, with_separators),
+        },
+    };
+
+    match verdict {
+        Some(verdict) => {
+            format!("{} {}", solution, format_verdict(verdict, with_color))
+        }
+        None => solution,
+    }
+}
+
+/// Renders the time spent on a step, or a dash if it did not run
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{:.2?}", duration),
+        None => "-".to_string(),
+    }
+}
+
+/// Returns the time spent solving all the given reports
+fn total_elapsed(results: &[PuzzleResult]) -> Duration {
+    results
+        .iter()
+        .map(|result| result.report.timings.total())
+        .sum()
 }
 
 /// Helper that gets the puzzle input and the solutions and prints them
@@ -61,6 +316,7 @@ impl OutputFormat {
 fn print_simple_results(
     puzzle: Puzzle,
     solutions: &[Solution; 2],
+    verdicts: &[Option<Verdict>; 2],
     with_color: bool,
 ) -> String {
     let mut error_string = String::from("Error:");
@@ -69,24 +325,20 @@ fn print_simple_results(
     }
     let error_string = error_string;
 
-    let p1_solution = match &solutions[0] {
-        Solution::NotExecuted => "Not executed".to_string(),
-        Solution::NotImplemented => "Not implemented".to_string(),
-        Solution::Err(err) => format!("{} {}", error_string, err).to_string(),
-        Solution::Value(x) => match with_color {
-            true => x.to_string().green().to_string(),
-            false => x.to_string(),
-        },
-    };
-    let p2_solution = match &solutions[1] {
-        Solution::NotExecuted => "Not executed".to_string(),
-        Solution::NotImplemented => "Not implemented".to_string(),
-        Solution::Err(err) => format!("{} {}", error_string, err).to_string(),
-        Solution::Value(x) => match with_color {
-            true => x.to_string().green().to_string(),
-            false => x.to_string(),
-        },
-    };
+    let p1_solution = format_solution(
+        &solutions[0],
+        verdicts[0],
+        &error_string,
+        with_color,
+        false,
+    );
+    let p2_solution = format_solution(
+        &solutions[1],
+        verdicts[1],
+        &error_string,
+        with_color,
+        false,
+    );
 
     [
         format!("Solutions {}/{:02}", puzzle.year(), puzzle.day()),
@@ -100,6 +352,7 @@ fn print_simple_results(
 fn print_tabulated_results(
     puzzle: Puzzle,
     solutions: &[Solution; 2],
+    verdicts: &[Option<Verdict>; 2],
     with_color: bool,
 ) -> String {
     let mut builder: Builder = Builder::default();
@@ -118,29 +371,22 @@ fn print_tabulated_results(
 
     builder.push_record(vec![table_header]);
 
-    for (index, solution) in solutions.iter().enumerate() {
+    for (index, (solution, verdict)) in
+        solutions.iter().zip(verdicts).enumerate()
+    {
         let mut header = format!("P{}", index + 1);
         if with_color {
             header = header.bold().to_string();
         }
         let header = header;
 
-        let solution = match solution {
-            Solution::NotExecuted => "Not executed".to_string(),
-            Solution::NotImplemented => "Not implemented".to_string(),
-            Solution::Err(err) => {
-                format!("{} {}", error_string, err).to_string()
-            }
-            Solution::Value(x) => {
-                let mut buf = Buffer::default();
-                buf.write_formatted(x, &Locale::en);
-
-                match with_color {
-                    true => buf.as_str().green().to_string(),
-                    false => buf.to_string(),
-                }
-            }
-        };
+        let solution = format_solution(
+            solution,
+            *verdict,
+            &error_string,
+            with_color,
+            true,
+        );
 
         builder.push_record(vec![header, solution]);
     }
@@ -158,6 +404,793 @@ fn print_tabulated_results(
     table.to_string()
 }
 
+/// Prints the results of every puzzle one after the other, each followed by
+/// the time spent on it, and the total time at the end
+fn print_simple_batch(results: &[PuzzleResult], with_color: bool) -> String {
+    let mut lines: Vec<String> = results
+        .iter()
+        .map(|result| {
+            format!(
+                "{}\nTime: {}",
+                print_simple_results(
+                    result.puzzle,
+                    &result.report.solutions,
+                    &result.verdicts,
+                    with_color
+                ),
+                format_duration(Some(result.report.timings.total()))
+            )
+        })
+        .collect();
+
+    lines.push(format!(
+        "Total: {}",
+        format_duration(Some(total_elapsed(results)))
+    ));
+
+    lines.join("\n\n")
+}
+
+/// Prints the results of every puzzle in a single table, one row per puzzle
+/// with the time spent on each part. The last row holds the total time.
+fn print_tabulated_batch(results: &[PuzzleResult], with_color: bool) -> String {
+    let mut builder: Builder = Builder::default();
+    let mut error_string = String::from("Error:");
+    if with_color {
+        error_string = error_string.red().bold().to_string();
+    }
+    let error_string = error_string;
+
+    let bold = |text: &str| match with_color {
+        true => text.bold().to_string(),
+        false => text.to_string(),
+    };
+
+    builder
+        .push_record(["Puzzle", "P1", "Time", "P2", "Time", "Total"].map(bold));
+
+    for PuzzleResult {
+        puzzle,
+        report,
+        verdicts: [p1_verdict, p2_verdict],
+    } in results
+    {
+        let [p1, p2] = &report.solutions;
+        let [p1_time, p2_time] = report.timings.parts;
+
+        builder.push_record(vec![
+            bold(&format!("{}/{:02}", puzzle.year(), puzzle.day())),
+            format_solution(p1, *p1_verdict, &error_string, with_color, true),
+            format_duration(p1_time),
+            format_solution(p2, *p2_verdict, &error_string, with_color, true),
+            format_duration(p2_time),
+            format_duration(Some(report.timings.total())),
+        ]);
+    }
+
+    builder.push_record(vec![
+        bold("Total"),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(Some(total_elapsed(results))),
+    ]);
+
+    let last_row = results.len() + 1;
+    let mut table = builder.build();
+    table
+        .with(Style::rounded())
+        .modify(Columns::new(2..3), Alignment::right())
+        .modify(Columns::new(4..), Alignment::right())
+        .modify(Rows::first(), Alignment::center())
+        .modify((last_row, 0), Span::column(5))
+        .with(BorderSpanCorrection);
+
+    table.to_string()
+}
+
+/// Which command wrote a JSON document
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum JsonKind {
+    Solve,
+    Bench,
+}
+
+/// A puzzle, as written by the JSON style
+#[derive(Serialize)]
+struct JsonResult {
+    schema: u32,
+    kind: JsonKind,
+    year: u32,
+    day: u32,
+    parts: [JsonPart; 2],
+}
+
+/// A part of a puzzle, as written by the JSON style
+#[derive(Serialize)]
+struct JsonPart {
+    part: u8,
+    status: JsonStatus,
+    /// The answer, as it would be submitted
+    answer: Option<String>,
+    error: Option<String>,
+    elapsed_ns: Option<u128>,
+    verdict: Option<Verdict>,
+}
+
+/// What happened to a part, as written by the JSON style
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum JsonStatus {
+    Solved,
+    Error,
+    NotExecuted,
+    NotImplemented,
+    SinglePart,
+}
+
+/// Returns the status of a solution, with its answer or its error, as
+/// written by the JSON style
+fn json_solution(
+    solution: &Solution,
+) -> (JsonStatus, Option<String>, Option<String>) {
+    match solution {
+        Solution::Value(answer) => {
+            (JsonStatus::Solved, Some(answer.to_string()), None)
+        }
+        Solution::Err(err) => (JsonStatus::Error, None, Some(err.to_string())),
+        Solution::NotExecuted => (JsonStatus::NotExecuted, None, None),
+        Solution::NotImplemented => (JsonStatus::NotImplemented, None, None),
+        Solution::SinglePart => (JsonStatus::SinglePart, None, None),
+    }
+}
+
+/// Renders the result of a puzzle as a single line JSON document
+fn print_json_result(result: &PuzzleResult) -> String {
+    let PuzzleResult {
+        puzzle,
+        report,
+        verdicts,
+    } = result;
+    let part = |index: usize| {
+        let (status, answer, error) = json_solution(&report.solutions[index]);
+
+        JsonPart {
+            part: index as u8 + 1,
+            status,
+            answer,
+            error,
+            elapsed_ns: report.timings.parts[index].map(|d| d.as_nanos()),
+            verdict: verdicts[index],
+        }
+    };
+
+    let document = JsonResult {
+        schema: JSON_SCHEMA_VERSION,
+        kind: JsonKind::Solve,
+        year: puzzle.year(),
+        day: puzzle.day(),
+        parts: [part(0), part(1)],
+    };
+
+    serde_json::to_string(&document).expect("the results are valid JSON")
+}
+
+/// Renders the results of every puzzle as newline delimited JSON: one
+/// document per line
+fn print_json_batch(results: &[PuzzleResult]) -> String {
+    results
+        .iter()
+        .map(print_json_result)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the results in a GitHub-flavoured Markdown table, one row per
+/// puzzle with the time spent on each part. With `with_total`, the last row
+/// holds the total time.
+fn print_markdown_table(results: &[PuzzleResult], with_total: bool) -> String {
+    let solution = |solution: &Solution, verdict: Option<Verdict>| {
+        escape_markdown_cell(&format_solution(
+            solution, verdict, "Error:", false, true,
+        ))
+    };
+    let mut lines = vec![
+        "| Puzzle | P1 | Time | P2 | Time | Total |".to_string(),
+        "| --- | --- | ---: | --- | ---: | ---: |".to_string(),
+    ];
+
+    for PuzzleResult {
+        puzzle,
+        report,
+        verdicts: [p1_verdict, p2_verdict],
+    } in results
+    {
+        let [p1, p2] = &report.solutions;
+        let [p1_time, p2_time] = report.timings.parts;
+
+        lines.push(format!(
+            "| {}/{:02} | {} | {} | {} | {} | {} |",
+            puzzle.year(),
+            puzzle.day(),
+            solution(p1, *p1_verdict),
+            format_duration(p1_time),
+            solution(p2, *p2_verdict),
+            format_duration(p2_time),
+            format_duration(Some(report.timings.total())),
+        ));
+    }
+
+    if with_total {
+        lines.push(format!(
+            "| **Total** | | | | | {} |",
+            format_duration(Some(total_elapsed(results)))
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Makes the text fit in a single Markdown table cell: pipes are escaped
+/// and line breaks become `<br>`
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Renders the results as CSV, with a header and one row per puzzle. The
+/// times are in nanoseconds, and empty for the steps that did not run.
+fn print_csv(results: &[PuzzleResult]) -> String {
+    let mut lines = vec![[
+        "year",
+        "day",
+        "p1",
+        "p1_verdict",
+        "p1_time_ns",
+        "p2",
+        "p2_verdict",
+        "p2_time_ns",
+        "total_ns",
+    ]
+    .join(",")];
+
+    for PuzzleResult {
+        puzzle,
+        report,
+        verdicts,
+    } in results
+    {
+        let mut fields =
+            vec![puzzle.year().to_string(), puzzle.day().to_string()];
+
+        for (index, solution) in report.solutions.iter().enumerate() {
+            fields
+                .push(format_solution(solution, None, "Error:", false, false));
+            fields.push(match verdicts[index] {
+                Some(Verdict::Confirmed) => "confirmed".to_string(),
+                Some(Verdict::Regression) => "regression".to_string(),
+                Some(Verdict::Unknown) => "unknown".to_string(),
+                None => String::new(),
+            });
+            fields.push(
+                report.timings.parts[index]
+                    .map(|d| d.as_nanos().to_string())
+                    .unwrap_or_default(),
+            );
+        }
+        fields.push(report.timings.total().as_nanos().to_string());
+
+        let fields: Vec<String> =
+            fields.iter().map(|field| escape_csv_field(field)).collect();
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n")
+}
+
+/// Quotes the field when it holds a comma, a quote or a line break,
+/// doubling its quotes
+fn escape_csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// Counts of the test cases of a JUnit test suite, and the seconds they took
+#[derive(Default)]
+struct JunitCounts {
+    tests: usize,
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+    time: f64,
+}
+
+impl JunitCounts {
+    fn add(&mut self, other: &JunitCounts) {
+        self.tests += other.tests;
+        self.failures += other.failures;
+        self.errors += other.errors;
+        self.skipped += other.skipped;
+        self.time += other.time;
+    }
+
+    fn attributes(&self) -> String {
+        format!(
+            r#"tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.6}""#,
+            self.tests, self.failures, self.errors, self.skipped, self.time
+        )
+    }
+}
+
+/// Renders the results as a JUnit XML report: one test suite per year, and
+/// one test case per part that was requested.
+///
+/// Errors become `<error>`, answers differing from the confirmed ones
+/// `<failure>`, and parts without a solution `<skipped>`.
+fn print_junit(results: &[PuzzleResult]) -> String {
+    let mut years: Vec<(u32, Vec<&PuzzleResult>)> = Vec::new();
+    for result in results {
+        match years.last_mut() {
+            Some((year, results)) if *year == result.puzzle.year() => {
+                results.push(result)
+            }
+            _ => years.push((result.puzzle.year(), vec![result])),
+        }
+    }
+
+    let mut total = JunitCounts::default();
+    let mut suites = Vec::new();
+    for (year, results) in years {
+        let mut counts = JunitCounts::default();
+        let mut cases = Vec::new();
+
+        for result in results {
+            for index in 0..2 {
+                if let Some(case) = junit_testcase(result, index, &mut counts) {
+                    cases.push(case);
+                }
+            }
+        }
+
+        total.add(&counts);
+        suites.push(format!(
+            "  <testsuite name=\"{}\" {}>\n{}  </testsuite>\n",
+            year,
+            counts.attributes(),
+            cases.concat()
+        ));
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"aoc\" {}>\n{}</testsuites>",
+        total.attributes(),
+        suites.concat()
+    )
+}
+
+/// Renders the test case of a part, counting it in `counts`. Parts that
+/// were not requested have none.
+fn junit_testcase(
+    result: &PuzzleResult,
+    index: usize,
+    counts: &mut JunitCounts,
+) -> Option<String> {
+    let solution = &result.report.solutions[index];
+    let child = match (solution, result.verdicts[index]) {
+        (Solution::NotExecuted, _) => return None,
+        (Solution::NotImplemented | Solution::SinglePart, _) => {
+            counts.skipped += 1;
+            Some(format!(
+                r#"<skipped message="{}"/>"#,
+                escape_xml(&format_solution(solution, None, "", false, false))
+            ))
+        }
+        (Solution::Err(err), _) => {
+            counts.errors += 1;
+            Some(format!(
+                "<error message=\"{}\" type=\"{}\">{}</error>",
+                escape_xml(&err.to_string()),
+                error_type(err),
+                escape_xml(&format_error(err, "Error:"))
+            ))
+        }
+        (Solution::Value(answer), Some(Verdict::Regression)) => {
+            counts.failures += 1;
+            Some(format!(
+                r#"<failure message="{} is not the confirmed answer"/>"#,
+                escape_xml(&answer.to_string())
+            ))
+        }
+        (Solution::Value(_), _) => None,
+    };
+
+    let time = result.report.timings.parts[index].unwrap_or_default();
+    counts.tests += 1;
+    counts.time += time.as_secs_f64();
+
+    let puzzle = result.puzzle;
+    let open = format!(
+        r#"    <testcase classname="y{}.day{:02}" name="part{}" time="{:.6}""#,
+        puzzle.year(),
+        puzzle.day(),
+        index + 1,
+        time.as_secs_f64()
+    );
+
+    Some(match child {
+        Some(child) => format!("{}>\n      {}\n    </testcase>\n", open, child),
+        None => format!("{}/>\n", open),
+    })
+}
+
+/// Returns the name of the kind of error, for the `type` attribute
+fn error_type(err: &SolverError) -> &'static str {
+    match err {
+        SolverError::Io(_) => "Io",
+        SolverError::Parse { .. } => "Parse",
+        SolverError::InvalidInput(_) => "InvalidInput",
+        SolverError::Timeout(_) => "Timeout",
+        SolverError::Panic(_) => "Panic",
+    }
+}
+
+/// Escapes the characters with a meaning in XML text and attributes, and
+/// replaces the ones XML 1.0 does not allow, like most control characters,
+/// with U+FFFD
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => {
+                escaped.push(char::REPLACEMENT_CHARACTER)
+            }
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// A step of a benchmark: its name, its solution, and its statistics.
+/// Parsing has no solution.
+type BenchStep<'a> = (&'static str, Option<&'a Solution>, Option<Stats>);
+
+/// Returns the steps of a benchmark, parsing first
+fn bench_steps(report: &BenchReport) -> [BenchStep<'_>; 3] {
+    [
+        ("Parse", None, report.parse),
+        ("P1", Some(&report.solutions[0]), report.parts[0]),
+        ("P2", Some(&report.solutions[1]), report.parts[1]),
+    ]
+}
+
+/// Renders the min, median, mean and standard deviation of a step, or
+/// dashes if it was not timed
+fn format_stats(stats: Option<Stats>) -> [String; 4] {
+    match stats {
+        Some(Stats {
+            min,
+            median,
+            mean,
+            stddev,
+        }) => [min, median, mean, stddev].map(|d| format_duration(Some(d))),
+        None => ["-", "-", "-", "-"].map(String::from),
+    }
+}
+
+/// Prints the benchmark of every puzzle one after the other, with one line
+/// per step
+fn print_simple_benchmarks(
+    results: &[BenchResult],
+    with_color: bool,
+) -> String {
+    let mut error_string = String::from("Error:");
+    if with_color {
+        error_string = error_string.red().bold().to_string();
+    }
+    let error_string = error_string;
+
+    results
+        .iter()
+        .map(|BenchResult { puzzle, report }| {
+            let mut lines = vec![format!(
+                "Benchmark {}/{:02}, {} runs",
+                puzzle.year(),
+                puzzle.day(),
+                report.runs
+            )];
+
+            for (name, solution, stats) in bench_steps(report) {
+                let solution = solution.map(|solution| {
+                    format_solution(
+                        solution,
+                        None,
+                        &error_string,
+                        with_color,
+                        false,
+                    )
+                });
+                let stats = stats.map(|stats| {
+                    let [min, median, mean, stddev] = format_stats(Some(stats));
+                    format!(
+                        "min {}, median {}, mean {} ± {}",
+                        min, median, mean, stddev
+                    )
+                });
+
+                match (solution, stats) {
+                    (Some(solution), Some(stats)) => {
+                        lines.push(format!("{}: {}, {}", name, solution, stats))
+                    }
+                    (Some(text), None) | (None, Some(text)) => {
+                        lines.push(format!("{}: {}", name, text))
+                    }
+                    (None, None) => {}
+                }
+            }
+
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Returns the rows of the benchmark tables: one per step, with the puzzle
+/// only on its first row
+fn bench_rows(
+    results: &[BenchResult],
+    error_string: &str,
+    with_color: bool,
+) -> Vec<[String; 7]> {
+    let mut rows = Vec::new();
+
+    for BenchResult { puzzle, report } in results {
+        for (index, (name, solution, stats)) in
+            bench_steps(report).into_iter().enumerate()
+        {
+            let [min, median, mean, stddev] = format_stats(stats);
+            let puzzle = match index {
+                0 => format!("{}/{:02}", puzzle.year(), puzzle.day()),
+                _ => String::new(),
+            };
+            let solution = solution
+                .map(|solution| {
+                    format_solution(
+                        solution,
+                        None,
+                        error_string,
+                        with_color,
+                        true,
+                    )
+                })
+                .unwrap_or_default();
+
+            rows.push([
+                puzzle,
+                name.to_string(),
+                solution,
+                min,
+                median,
+                mean,
+                stddev,
+            ]);
+        }
+    }
+
+    rows
+}
+
+/// Header of the benchmark tables
+const BENCH_HEADER: [&str; 7] = [
+    "Puzzle", "Step", "Answer", "Min", "Median", "Mean", "Std dev",
+];
+
+/// Prints the benchmarks in a single table, one row per step of each
+/// puzzle
+fn print_tabulated_benchmarks(
+    results: &[BenchResult],
+    with_color: bool,
+) -> String {
+    let mut builder: Builder = Builder::default();
+    let mut error_string = String::from("Error:");
+    if with_color {
+        error_string = error_string.red().bold().to_string();
+    }
+    let error_string = error_string;
+
+    let bold = |text: &str| match with_color && !text.is_empty() {
+        true => text.bold().to_string(),
+        false => text.to_string(),
+    };
+
+    builder.push_record(BENCH_HEADER.map(bold));
+    for [puzzle, step, rest @ ..] in
+        bench_rows(results, &error_string, with_color)
+    {
+        builder
+            .push_record([bold(&puzzle), bold(&step)].into_iter().chain(rest));
+    }
+
+    let mut table = builder.build();
+    table
+        .with(Style::rounded())
+        .modify(Columns::new(3..), Alignment::right())
+        .modify(Rows::first(), Alignment::center());
+
+    table.to_string()
+}
+
+/// Renders the benchmarks in a GitHub-flavoured Markdown table, one row per
+/// step of each puzzle
+fn print_markdown_benchmarks(results: &[BenchResult]) -> String {
+    let mut lines = vec![
+        format!("| {} |", BENCH_HEADER.join(" | ")),
+        "| --- | --- | --- | ---: | ---: | ---: | ---: |".to_string(),
+    ];
+
+    for row in bench_rows(results, "Error:", false) {
+        let cells: Vec<String> =
+            row.iter().map(|cell| escape_markdown_cell(cell)).collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+
+    lines.join("\n")
+}
+
+/// Timing statistics of a step, as written by the JSON style
+#[derive(Serialize)]
+struct JsonStats {
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+}
+
+impl From<Stats> for JsonStats {
+    fn from(stats: Stats) -> Self {
+        Self {
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        }
+    }
+}
+
+/// The benchmark of a puzzle, as written by the JSON style
+#[derive(Serialize)]
+struct JsonBenchmark {
+    schema: u32,
+    kind: JsonKind,
+    year: u32,
+    day: u32,
+    runs: usize,
+    parse: Option<JsonStats>,
+    parts: [JsonBenchPart; 2],
+}
+
+/// The benchmark of a part, as written by the JSON style
+#[derive(Serialize)]
+struct JsonBenchPart {
+    part: u8,
+    status: JsonStatus,
+    answer: Option<String>,
+    error: Option<String>,
+    stats: Option<JsonStats>,
+}
+
+/// Renders the benchmarks as newline delimited JSON: one document per
+/// puzzle and line
+fn print_json_benchmarks(results: &[BenchResult]) -> String {
+    results
+        .iter()
+        .map(|BenchResult { puzzle, report }| {
+            let part = |index: usize| {
+                let (status, answer, error) =
+                    json_solution(&report.solutions[index]);
+
+                JsonBenchPart {
+                    part: index as u8 + 1,
+                    status,
+                    answer,
+                    error,
+                    stats: report.parts[index].map(JsonStats::from),
+                }
+            };
+
+            let document = JsonBenchmark {
+                schema: JSON_SCHEMA_VERSION,
+                kind: JsonKind::Bench,
+                year: puzzle.year(),
+                day: puzzle.day(),
+                runs: report.runs,
+                parse: report.parse.map(JsonStats::from),
+                parts: [part(0), part(1)],
+            };
+
+            serde_json::to_string(&document)
+                .expect("the benchmarks are valid JSON")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the benchmarks as CSV, with a header and one row per step of
+/// each puzzle. The times are in nanoseconds, and empty for the steps that
+/// were not timed.
+fn print_csv_benchmarks(results: &[BenchResult]) -> String {
+    let mut lines = vec![[
+        "year",
+        "day",
+        "step",
+        "answer",
+        "runs",
+        "min_ns",
+        "median_ns",
+        "mean_ns",
+        "stddev_ns",
+    ]
+    .join(",")];
+
+    for BenchResult { puzzle, report } in results {
+        for (name, solution, stats) in bench_steps(report) {
+            let mut fields = vec![
+                puzzle.year().to_string(),
+                puzzle.day().to_string(),
+                name.to_lowercase(),
+                solution
+                    .map(|solution| {
+                        format_solution(solution, None, "Error:", false, false)
+                    })
+                    .unwrap_or_default(),
+                report.runs.to_string(),
+            ];
+            fields.extend(match stats {
+                Some(stats) => {
+                    [stats.min, stats.median, stats.mean, stats.stddev]
+                        .map(|d| d.as_nanos().to_string())
+                }
+                None => Default::default(),
+            });
+
+            let fields: Vec<String> =
+                fields.iter().map(|field| escape_csv_field(field)).collect();
+            lines.push(fields.join(","));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Turns a benchmark into the result of a single run, taking the median
+/// time of each step
+fn median_result(result: &BenchResult) -> PuzzleResult {
+    let median = |stats: Option<Stats>| stats.map(|stats| stats.median);
+
+    PuzzleResult {
+        puzzle: result.puzzle,
+        report: Report {
+            solutions: result.report.solutions.clone(),
+            timings: Timings {
+                parse: median(result.report.parse),
+                parts: result.report.parts.map(median),
+            },
+        },
+        verdicts: [None, None],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +1201,8 @@ mod tests {
     static PUZZLE: LazyLock<Puzzle> =
         LazyLock::new(|| Puzzle::new(2024, 1).unwrap());
 
+    const NO_VERDICTS: [Option<Verdict>; 2] = [None, None];
+
     static NOT_IMPLEMENTED_SOLUTIONS: LazyLock<[Solution; 2]> =
         LazyLock::new(|| [Solution::NotImplemented, Solution::NotImplemented]);
 
@@ -176,13 +1211,91 @@ mod tests {
 
     static ERROR_SOLUTIONS: LazyLock<[Solution; 2]> = LazyLock::new(|| {
         [
-            Solution::Err(String::from("ERR 1")),
-            Solution::Err(String::from("ERR 2")),
+            Solution::Err(SolverError::InvalidInput(String::from("ERR 1"))),
+            Solution::Err(SolverError::InvalidInput(String::from("ERR 2"))),
         ]
     });
 
-    static VALUE_SOLUTIONS: LazyLock<[Solution; 2]> =
-        LazyLock::new(|| [Solution::Value(12), Solution::Value(24)]);
+    static VALUE_SOLUTIONS: LazyLock<[Solution; 2]> = LazyLock::new(|| {
+        [
+            Solution::Value(Answer::Unsigned(12)),
+            Solution::Value(Answer::Unsigned(24)),
+        ]
+    });
+
+    static RICH_VALUE_SOLUTIONS: LazyLock<[Solution; 2]> =
+        LazyLock::new(|| {
+            [
+                Solution::Value(Answer::Signed(-1234567)),
+                Solution::Value(Answer::Text("1,2,3".to_string())),
+            ]
+        });
+
+    static PARSE_ERROR_SOLUTIONS: LazyLock<[Solution; 2]> =
+        LazyLock::new(|| {
+            [
+                Solution::Err(SolverError::parse(12, 3, "1 x", "NaN")),
+                Solution::NotExecuted,
+            ]
+        });
+
+    mod format_error {
+        use super::*;
+
+        #[test]
+        fn test_parse_error_context() {
+            let err = SolverError::parse(12, 3, "1 x", "NaN");
+            let expected = [
+                "Error: parse error at line 12, column 3: NaN",
+                "12 | 1 x",
+                "   |   ^",
+            ]
+            .join("\n");
+
+            assert_eq!(format_error(&err, "Error:"), expected);
+        }
+
+        #[test]
+        fn test_other_errors() {
+            let err = SolverError::Io("missing".to_string());
+
+            assert_eq!(
+                format_error(&err, "Error:"),
+                "Error: I/O error: missing"
+            );
+        }
+    }
+
+    mod format_answer {
+        use super::*;
+
+        #[test]
+        fn test_numeric_separators() {
+            let unsigned = Answer::Unsigned(1234567);
+            let signed = Answer::Signed(-1234567);
+            let big = Answer::Big(12345678901234567890123);
+
+            assert_eq!(format_answer(&unsigned, true), "1,234,567");
+            assert_eq!(format_answer(&signed, true), "-1,234,567");
+            assert_eq!(
+                format_answer(&big, true),
+                "12,345,678,901,234,567,890,123"
+            );
+        }
+
+        #[test]
+        fn test_numeric_without_separators() {
+            assert_eq!(format_answer(&Answer::Signed(-1234), false), "-1234");
+        }
+
+        #[test]
+        fn test_text_is_kept() {
+            let text = Answer::Text("1234,5678".to_string());
+
+            assert_eq!(format_answer(&text, true), "1234,5678");
+            assert_eq!(format_answer(&text, false), "1234,5678");
+        }
+    }
 
     mod print_simple_results {
         use super::*;
@@ -195,6 +1308,7 @@ mod tests {
             let result = print_simple_results(
                 *PUZZLE,
                 &NOT_IMPLEMENTED_SOLUTIONS,
+                &NO_VERDICTS,
                 false,
             );
 
@@ -206,8 +1320,12 @@ mod tests {
             let expected =
                 ["Solutions 2024/01", "Not executed", "Not executed"]
                     .join("\n");
-            let result =
-                print_simple_results(*PUZZLE, &NOT_EXECUTED_SOLUTIONS, false);
+            let result = print_simple_results(
+                *PUZZLE,
+                &NOT_EXECUTED_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
 
             assert_eq!(expected, result);
         }
@@ -217,7 +1335,32 @@ mod tests {
             let expected =
                 ["Solutions 2024/01", "Error: ERR 1", "Error: ERR 2"]
                     .join("\n");
-            let result = print_simple_results(*PUZZLE, &ERROR_SOLUTIONS, false);
+            let result = print_simple_results(
+                *PUZZLE,
+                &ERROR_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
+
+            assert_eq!(expected, result);
+        }
+
+        #[test]
+        fn test_parse_error_solutions() {
+            let expected = [
+                "Solutions 2024/01",
+                "Error: parse error at line 12, column 3: NaN",
+                "12 | 1 x",
+                "   |   ^",
+                "Not executed",
+            ]
+            .join("\n");
+            let result = print_simple_results(
+                *PUZZLE,
+                &PARSE_ERROR_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
 
             assert_eq!(expected, result);
         }
@@ -232,7 +1375,12 @@ mod tests {
                 "\u{1b}[1m\u{1b}[31mError:\u{1b}[39m\u{1b}[0m ERR 2",
             ]
             .join("\n");
-            let result = print_simple_results(*PUZZLE, &ERROR_SOLUTIONS, true);
+            let result = print_simple_results(
+                *PUZZLE,
+                &ERROR_SOLUTIONS,
+                &NO_VERDICTS,
+                true,
+            );
 
             assert_eq!(expected, result);
         }
@@ -240,7 +1388,26 @@ mod tests {
         #[test]
         fn test_value_solutions() {
             let expected = ["Solutions 2024/01", "12", "24"].join("\n");
-            let result = print_simple_results(*PUZZLE, &VALUE_SOLUTIONS, false);
+            let result = print_simple_results(
+                *PUZZLE,
+                &VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
+
+            assert_eq!(expected, result);
+        }
+
+        #[test]
+        fn test_rich_value_solutions() {
+            let expected =
+                ["Solutions 2024/01", "-1234567", "1,2,3"].join("\n");
+            let result = print_simple_results(
+                *PUZZLE,
+                &RICH_VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
 
             assert_eq!(expected, result);
         }
@@ -255,7 +1422,12 @@ mod tests {
                 "\u{1b}[32m24\u{1b}[39m",
             ]
             .join("\n");
-            let result = print_simple_results(*PUZZLE, &VALUE_SOLUTIONS, true);
+            let result = print_simple_results(
+                *PUZZLE,
+                &VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                true,
+            );
 
             assert_eq!(expected, result);
         }
@@ -278,6 +1450,7 @@ mod tests {
             let result = print_tabulated_results(
                 *PUZZLE,
                 &NOT_IMPLEMENTED_SOLUTIONS,
+                &NO_VERDICTS,
                 false,
             );
 
@@ -299,6 +1472,7 @@ mod tests {
             let result = print_tabulated_results(
                 *PUZZLE,
                 &NOT_IMPLEMENTED_SOLUTIONS,
+                &NO_VERDICTS,
                 true,
             );
 
@@ -319,6 +1493,7 @@ mod tests {
             let result = print_tabulated_results(
                 *PUZZLE,
                 &NOT_EXECUTED_SOLUTIONS,
+                &NO_VERDICTS,
                 false,
             );
 
@@ -337,8 +1512,12 @@ mod tests {
                 "╰────┴──────────────╯",
             ]
             .join("\n");
-            let result =
-                print_tabulated_results(*PUZZLE, &NOT_EXECUTED_SOLUTIONS, true);
+            let result = print_tabulated_results(
+                *PUZZLE,
+                &NOT_EXECUTED_SOLUTIONS,
+                &NO_VERDICTS,
+                true,
+            );
 
             assert_eq!(expected, result);
         }
@@ -354,8 +1533,12 @@ mod tests {
                 "╰────┴──────────────╯",
             ]
             .join("\n");
-            let result =
-                print_tabulated_results(*PUZZLE, &ERROR_SOLUTIONS, false);
+            let result = print_tabulated_results(
+                *PUZZLE,
+                &ERROR_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
 
             assert_eq!(expected, result);
         }
@@ -371,8 +1554,12 @@ mod tests {
                 "╰────┴──────────────╯",
             ]
             .join("\n");
-            let result =
-                print_tabulated_results(*PUZZLE, &ERROR_SOLUTIONS, true);
+            let result = print_tabulated_results(
+                *PUZZLE,
+                &ERROR_SOLUTIONS,
+                &NO_VERDICTS,
+                true,
+            );
 
             assert_eq!(expected, result);
         }
@@ -388,8 +1575,33 @@ mod tests {
                 "╰─────────┴─────────╯",
             ]
             .join("\n");
-            let result =
-                print_tabulated_results(*PUZZLE, &VALUE_SOLUTIONS, false);
+            let result = print_tabulated_results(
+                *PUZZLE,
+                &VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
+
+            assert_eq!(expected, result);
+        }
+
+        #[test]
+        fn test_rich_value_solutions() {
+            let expected = [
+                "╭───────────────────╮",
+                "│ Solutions 2024/01 │",
+                "├─────┬─────────────┤",
+                "│ P1  │ -1,234,567  │",
+                "│ P2  │ 1,2,3       │",
+                "╰─────┴─────────────╯",
+            ]
+            .join("\n");
+            let result = print_tabulated_results(
+                *PUZZLE,
+                &RICH_VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
 
             assert_eq!(expected, result);
         }
@@ -405,20 +1617,586 @@ mod tests {
                 "╰─────────┴─────────╯",
             ]
             .join("\n");
+            let result = print_tabulated_results(
+                *PUZZLE,
+                &VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                true,
+            );
+
+            assert_eq!(expected, result);
+        }
+    }
+
+    static BATCH_RESULTS: LazyLock<Vec<PuzzleResult>> = LazyLock::new(|| {
+        let timings = Timings {
+            parse: Some(Duration::from_micros(500)),
+            parts: [
+                Some(Duration::from_millis(1)),
+                Some(Duration::from_millis(2)),
+            ],
+        };
+
+        vec![
+            PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: VALUE_SOLUTIONS.clone(),
+                    timings,
+                },
+                verdicts: [Some(Verdict::Confirmed), Some(Verdict::Regression)],
+            },
+            PuzzleResult {
+                puzzle: Puzzle::new(2024, 2).unwrap(),
+                report: Report {
+                    solutions: ERROR_SOLUTIONS.clone(),
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            },
+        ]
+    });
+
+    mod format_verdict {
+        use super::*;
+
+        #[test]
+        fn test_marks() {
+            assert_eq!(format_verdict(Verdict::Confirmed, false), "✔");
+            assert_eq!(format_verdict(Verdict::Regression, false), "✘");
+            assert_eq!(format_verdict(Verdict::Unknown, false), "?");
+        }
+
+        #[test]
+        fn test_marks_with_color_variant() {
+            assert_eq!(
+                format_verdict(Verdict::Confirmed, true),
+                "\u{1b}[32m✔\u{1b}[39m"
+            );
+        }
+
+        #[test]
+        fn test_marked_solutions() {
+            let verdicts = [Some(Verdict::Unknown), Some(Verdict::Regression)];
+            let expected = ["Solutions 2024/01", "12 ?", "Error: ERR 2 ✘"];
+            let solutions =
+                [VALUE_SOLUTIONS[0].clone(), ERROR_SOLUTIONS[1].clone()];
             let result =
-                print_tabulated_results(*PUZZLE, &VALUE_SOLUTIONS, true);
+                print_simple_results(*PUZZLE, &solutions, &verdicts, false);
+
+            assert_eq!(expected.join("\n"), result);
+        }
+    }
+
+    mod print_batch {
+        use super::*;
+
+        #[test]
+        fn test_simple_batch() {
+            let expected = [
+                "Solutions 2024/01\n12 ✔\n24 ✘\nTime: 3.50ms",
+                "Solutions 2024/02\nError: ERR 1\nError: ERR 2\nTime: 0.00ns",
+                "Total: 3.50ms",
+            ]
+            .join("\n\n");
+            let result = print_simple_batch(&BATCH_RESULTS, false);
 
             assert_eq!(expected, result);
         }
+
+        #[test]
+        fn test_tabulated_batch() {
+            let expected = [
+                "╭─────────┬──────────────┬────────┬──────────────┬────────┬────────╮",
+                "│ Puzzle  │      P1      │  Time  │      P2      │  Time  │ Total  │",
+                "├─────────┼──────────────┼────────┼──────────────┼────────┼────────┤",
+                "│ 2024/01 │ 12 ✔         │ 1.00ms │ 24 ✘         │ 2.00ms │ 3.50ms │",
+                "│ 2024/02 │ Error: ERR 1 │      - │ Error: ERR 2 │      - │ 0.00ns │",
+                "│ Total                                                   │ 3.50ms │",
+                "╰─────────────────────────────────────────────────────────┴────────╯",
+            ]
+            .join("\n");
+            let result = print_tabulated_batch(&BATCH_RESULTS, false);
+
+            assert_eq!(expected, result);
+        }
+
+        #[test]
+        fn test_count_verdicts() {
+            assert_eq!(count_verdicts(&BATCH_RESULTS), [1, 1, 0]);
+            assert_eq!(count_verdicts(&[]), [0, 0, 0]);
+        }
+
+        #[test]
+        fn test_summary() {
+            assert_eq!(
+                format_summary(&BATCH_RESULTS, false),
+                "Verified: 1 passed, 1 failed, 0 without a confirmed answer"
+            );
+        }
+
+        #[test]
+        fn test_empty_batch() {
+            assert_eq!(print_simple_batch(&[], false), "Total: 0.00ns");
+        }
+    }
+
+    mod print_json {
+        use super::*;
+
+        #[test]
+        fn test_json_result() {
+            let expected = concat!(
+                r#"{"schema":1,"kind":"solve","year":2024,"day":1,"parts":["#,
+                r#"{"part":1,"status":"solved","answer":"12","error":null,"elapsed_ns":1000000,"verdict":"confirmed"},"#,
+                r#"{"part":2,"status":"solved","answer":"24","error":null,"elapsed_ns":2000000,"verdict":"regression"}"#,
+                r#"]}"#
+            );
+
+            assert_eq!(print_json_result(&BATCH_RESULTS[0]), expected);
+        }
+
+        #[test]
+        fn test_json_errors() {
+            let expected = concat!(
+                r#"{"schema":1,"kind":"solve","year":2024,"day":2,"parts":["#,
+                r#"{"part":1,"status":"error","answer":null,"error":"ERR 1","elapsed_ns":null,"verdict":null},"#,
+                r#"{"part":2,"status":"error","answer":null,"error":"ERR 2","elapsed_ns":null,"verdict":null}"#,
+                r#"]}"#
+            );
+
+            assert_eq!(print_json_result(&BATCH_RESULTS[1]), expected);
+        }
+
+        #[test]
+        fn test_json_statuses() {
+            let result = PuzzleResult {
+                puzzle: Puzzle::new(2024, 25).unwrap(),
+                report: Report {
+                    solutions: [Solution::NotImplemented, Solution::SinglePart],
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+            let document: serde_json::Value =
+                serde_json::from_str(&print_json_result(&result)).unwrap();
+
+            assert_eq!(document["parts"][0]["status"], "not_implemented");
+            assert_eq!(document["parts"][1]["status"], "single_part");
+        }
+
+        #[test]
+        fn test_json_text_answers_are_escaped() {
+            let result = PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: [
+                        Solution::Value(Answer::Text("\"a\"\nb".to_string())),
+                        Solution::Value(Answer::Big(-(1 << 100))),
+                    ],
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+            let document: serde_json::Value =
+                serde_json::from_str(&print_json_result(&result)).unwrap();
+
+            assert_eq!(document["parts"][0]["answer"], "\"a\"\nb");
+            assert_eq!(
+                document["parts"][1]["answer"],
+                "-1267650600228229401496703205376"
+            );
+        }
+
+        #[test]
+        fn test_ndjson_batch() {
+            let result = print_json_batch(&BATCH_RESULTS);
+            let lines: Vec<&str> = result.lines().collect();
+
+            assert_eq!(lines.len(), 2);
+            assert_eq!(lines[0], print_json_result(&BATCH_RESULTS[0]));
+            assert_eq!(lines[1], print_json_result(&BATCH_RESULTS[1]));
+        }
+
+        #[test]
+        fn test_json_verification_has_no_summary() {
+            let format = *new().set_style(StyleFormat::Json);
+
+            assert_eq!(
+                format.format_verification(&BATCH_RESULTS),
+                print_json_batch(&BATCH_RESULTS)
+            );
+            assert!(format.is_machine_readable());
+        }
+    }
+
+    mod print_markdown {
+        use super::*;
+
+        #[test]
+        fn test_markdown_batch() {
+            let expected = [
+                "| Puzzle | P1 | Time | P2 | Time | Total |",
+                "| --- | --- | ---: | --- | ---: | ---: |",
+                "| 2024/01 | 12 ✔ | 1.00ms | 24 ✘ | 2.00ms | 3.50ms |",
+                "| 2024/02 | Error: ERR 1 | - | Error: ERR 2 | - | 0.00ns |",
+                "| **Total** | | | | | 3.50ms |",
+            ]
+            .join("\n");
+
+            assert_eq!(print_markdown_table(&BATCH_RESULTS, true), expected);
+        }
+
+        #[test]
+        fn test_markdown_single_puzzle() {
+            let result = print_markdown_table(&BATCH_RESULTS[..1], false);
+
+            assert_eq!(result.lines().count(), 3);
+            assert!(!result.contains("Total**"));
+        }
+
+        #[test]
+        fn test_markdown_escapes_errors() {
+            let result = PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: [
+                        Solution::Err(SolverError::parse(1, 3, "a|b", "NaN")),
+                        Solution::Value(Answer::Text("|x|".to_string())),
+                    ],
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+            let expected = concat!(
+                r"| 2024/01 | Error: parse error at line 1, column 3: NaN",
+                r"<br>1 \| a\|b<br>  \|   ^ | - | \|x\| | - | 0.00ns |"
+            );
+
+            assert_eq!(
+                print_markdown_table(&[result], false).lines().nth(2),
+                Some(expected)
+            );
+        }
+    }
+
+    mod print_csv {
+        use super::*;
+
+        #[test]
+        fn test_csv_batch() {
+            let expected = [
+                "year,day,p1,p1_verdict,p1_time_ns,p2,p2_verdict,p2_time_ns,total_ns",
+                "2024,1,12,confirmed,1000000,24,regression,2000000,3500000",
+                "2024,2,Error: ERR 1,,,Error: ERR 2,,,0",
+            ]
+            .join("\n");
+
+            assert_eq!(print_csv(&BATCH_RESULTS), expected);
+        }
+
+        #[test]
+        fn test_csv_quotes_fields() {
+            let result = PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: [
+                        Solution::Value(Answer::Text("1,2,3".to_string())),
+                        Solution::Err(SolverError::InvalidInput(
+                            "say \"hi\", twice".to_string(),
+                        )),
+                    ],
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+
+            assert_eq!(
+                print_csv(&[result]).lines().nth(1),
+                Some(r#"2024,1,"1,2,3",,,"Error: say ""hi"", twice",,,0"#)
+            );
+        }
+
+        #[test]
+        fn test_csv_numbers_have_no_separators() {
+            let result = PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: RICH_VALUE_SOLUTIONS.clone(),
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+
+            assert!(print_csv(&[result]).contains(",-1234567,"));
+        }
+    }
+
+    mod print_junit {
+        use super::*;
+
+        #[test]
+        fn test_junit_batch() {
+            let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="aoc" tests="4" failures="1" errors="2" skipped="0" time="0.003000">
+  <testsuite name="2024" tests="4" failures="1" errors="2" skipped="0" time="0.003000">
+    <testcase classname="y2024.day01" name="part1" time="0.001000"/>
+    <testcase classname="y2024.day01" name="part2" time="0.002000">
+      <failure message="24 is not the confirmed answer"/>
+    </testcase>
+    <testcase classname="y2024.day02" name="part1" time="0.000000">
+      <error message="ERR 1" type="InvalidInput">Error: ERR 1</error>
+    </testcase>
+    <testcase classname="y2024.day02" name="part2" time="0.000000">
+      <error message="ERR 2" type="InvalidInput">Error: ERR 2</error>
+    </testcase>
+  </testsuite>
+</testsuites>"#;
+
+            assert_eq!(print_junit(&BATCH_RESULTS), expected);
+        }
+
+        #[test]
+        fn test_junit_suite_per_year() {
+            let result = |year, solutions: &[Solution; 2]| PuzzleResult {
+                puzzle: Puzzle::new(year, 1).unwrap(),
+                report: Report {
+                    solutions: solutions.clone(),
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+            let results = [
+                result(2023, &NOT_IMPLEMENTED_SOLUTIONS),
+                result(2024, &NOT_EXECUTED_SOLUTIONS),
+            ];
+            let report = print_junit(&results);
+
+            assert!(report.contains(r#"<testsuites name="aoc" tests="2" failures="0" errors="0" skipped="2""#));
+            assert!(report.contains(r#"<testsuite name="2023" tests="2" failures="0" errors="0" skipped="2""#));
+            assert!(report.contains(r#"<testsuite name="2024" tests="0" failures="0" errors="0" skipped="0""#));
+            assert!(report.contains(r#"<skipped message="Not implemented"/>"#));
+        }
+
+        #[test]
+        fn test_junit_escapes_errors() {
+            let result = PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: PARSE_ERROR_SOLUTIONS.clone(),
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+            let solutions = [
+                Solution::Err(SolverError::Panic("a < b & \"c\"".into())),
+                Solution::NotExecuted,
+            ];
+            let panicked = PuzzleResult {
+                report: Report {
+                    solutions,
+                    timings: Timings::default(),
+                },
+                ..result.clone()
+            };
+
+            assert!(print_junit(&[result]).contains(
+                "<error message=\"parse error at line 12, column 3: NaN\" \
+                 type=\"Parse\">Error: parse error at line 12, column 3: NaN\n\
+                 12 | 1 x\n   |   ^</error>"
+            ));
+            assert!(print_junit(&[panicked]).contains(
+                "message=\"solver panicked: a &lt; b &amp; &quot;c&quot;\""
+            ));
+        }
+
+        #[test]
+        fn test_junit_replaces_invalid_characters() {
+            let solutions = [
+                Solution::Err(SolverError::InvalidInput(
+                    "bad \u{1b}[0m\tbyte\u{0}".into(),
+                )),
+                Solution::NotExecuted,
+            ];
+            let result = PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions,
+                    timings: Timings::default(),
+                },
+                verdicts: NO_VERDICTS,
+            };
+
+            assert!(print_junit(&[result])
+                .contains("message=\"bad \u{fffd}[0m\tbyte\u{fffd}\""));
+        }
+    }
+
+    mod print_benchmarks {
+        use super::*;
+
+        fn stats(micros: u64) -> Option<Stats> {
+            let micros = Duration::from_micros(micros);
+
+            Some(Stats {
+                min: micros,
+                median: micros * 2,
+                mean: micros * 3,
+                stddev: micros / 2,
+            })
+        }
+
+        static BENCH_RESULTS: LazyLock<[BenchResult; 2]> =
+            LazyLock::new(|| {
+                [
+                    BenchResult {
+                        puzzle: *PUZZLE,
+                        report: BenchReport {
+                            solutions: [
+                                Solution::Value(Answer::Unsigned(1234)),
+                                Solution::NotImplemented,
+                            ],
+                            runs: 10,
+                            parse: stats(1),
+                            parts: [stats(10), None],
+                        },
+                    },
+                    BenchResult {
+                        puzzle: Puzzle::new(2024, 2).unwrap(),
+                        report: BenchReport {
+                            solutions: ERROR_SOLUTIONS.clone(),
+                            runs: 0,
+                            parse: None,
+                            parts: [None, None],
+                        },
+                    },
+                ]
+            });
+
+        #[test]
+        fn test_simple_benchmarks() {
+            let expected = [
+                "Benchmark 2024/01, 10 runs",
+                "Parse: min 1.00µs, median 2.00µs, mean 3.00µs ± 500.00ns",
+                "P1: 1234, min 10.00µs, median 20.00µs, mean 30.00µs ± 5.00µs",
+                "P2: Not implemented",
+                "",
+                "Benchmark 2024/02, 0 runs",
+                "P1: Error: ERR 1",
+                "P2: Error: ERR 2",
+            ]
+            .join("\n");
+
+            assert_eq!(
+                print_simple_benchmarks(&*BENCH_RESULTS, false),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_tabulated_benchmarks() {
+            let expected = [
+                "╭─────────┬───────┬─────────────────┬─────────┬─────────┬─────────┬──────────╮",
+                "│ Puzzle  │ Step  │     Answer      │   Min   │ Median  │  Mean   │ Std dev  │",
+                "├─────────┼───────┼─────────────────┼─────────┼─────────┼─────────┼──────────┤",
+                "│ 2024/01 │ Parse │                 │  1.00µs │  2.00µs │  3.00µs │ 500.00ns │",
+                "│         │ P1    │ 1,234           │ 10.00µs │ 20.00µs │ 30.00µs │   5.00µs │",
+                "│         │ P2    │ Not implemented │       - │       - │       - │        - │",
+                "│ 2024/02 │ Parse │                 │       - │       - │       - │        - │",
+                "│         │ P1    │ Error: ERR 1    │       - │       - │       - │        - │",
+                "│         │ P2    │ Error: ERR 2    │       - │       - │       - │        - │",
+                "╰─────────┴───────┴─────────────────┴─────────┴─────────┴─────────┴──────────╯",
+            ]
+            .join("\n");
+
+            assert_eq!(
+                print_tabulated_benchmarks(&*BENCH_RESULTS, false),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_markdown_benchmarks() {
+            let expected = [
+                "| Puzzle | Step | Answer | Min | Median | Mean | Std dev |",
+                "| --- | --- | --- | ---: | ---: | ---: | ---: |",
+                "| 2024/01 | Parse |  | 1.00µs | 2.00µs | 3.00µs | 500.00ns |",
+                "|  | P1 | 1,234 | 10.00µs | 20.00µs | 30.00µs | 5.00µs |",
+                "|  | P2 | Not implemented | - | - | - | - |",
+            ]
+            .join("\n");
+
+            assert_eq!(
+                print_markdown_benchmarks(&BENCH_RESULTS[..1]),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_csv_benchmarks() {
+            let expected = [
+                "year,day,step,answer,runs,min_ns,median_ns,mean_ns,stddev_ns",
+                "2024,1,parse,,10,1000,2000,3000,500",
+                "2024,1,p1,1234,10,10000,20000,30000,5000",
+                "2024,1,p2,Not implemented,10,,,,",
+                "2024,2,parse,,0,,,,",
+                "2024,2,p1,Error: ERR 1,0,,,,",
+                "2024,2,p2,Error: ERR 2,0,,,,",
+            ]
+            .join("\n");
+
+            assert_eq!(print_csv_benchmarks(&*BENCH_RESULTS), expected);
+        }
+
+        #[test]
+        fn test_json_benchmarks() {
+            let expected = concat!(
+                r#"{"schema":1,"kind":"bench","year":2024,"day":1,"runs":10,"#,
+                r#""parse":{"min_ns":1000,"median_ns":2000,"mean_ns":3000,"stddev_ns":500},"parts":["#,
+                r#"{"part":1,"status":"solved","answer":"1234","error":null,"stats":{"min_ns":10000,"median_ns":20000,"mean_ns":30000,"stddev_ns":5000}},"#,
+                r#"{"part":2,"status":"not_implemented","answer":null,"error":null,"stats":null}"#,
+                r#"]}"#
+            );
+            let result = print_json_benchmarks(&*BENCH_RESULTS);
+            let lines: Vec<&str> = result.lines().collect();
+
+            assert_eq!(lines.len(), 2);
+            assert_eq!(lines[0], expected);
+        }
+
+        #[test]
+        fn test_junit_benchmarks_take_the_median() {
+            let result = new()
+                .set_style(StyleFormat::Junit)
+                .format_benchmarks(&*BENCH_RESULTS);
+
+            assert!(result.contains(
+                r#"<testcase classname="y2024.day01" name="part1" time="0.000020"/>"#
+            ));
+            assert!(result
+                .contains(r#"tests="4" failures="0" errors="2" skipped="1""#));
+        }
     }
 
     mod output_format {
         use crate::formatter::{
-            new, print_simple_results, print_tabulated_results, OutputFormat,
-            StyleFormat,
+            new, print_json_result, print_simple_results,
+            print_tabulated_results, OutputFormat, PuzzleResult, StyleFormat,
         };
-
-        use super::{PUZZLE, VALUE_SOLUTIONS};
+        use crate::solvers::Report;
+
+        use super::{NO_VERDICTS, PUZZLE, VALUE_SOLUTIONS};
+
+        fn result() -> PuzzleResult {
+            PuzzleResult {
+                puzzle: *PUZZLE,
+                report: Report {
+                    solutions: VALUE_SOLUTIONS.clone(),
+                    timings: Default::default(),
+                },
+                verdicts: NO_VERDICTS,
+            }
+        }
 
         #[test]
         fn test_new_equals_default() {
@@ -432,10 +2210,10 @@ mod tests {
         fn test_set_color_builder() {
             let mut builder1 = OutputFormat::default();
 
-            assert_eq!(builder1.color, false);
+            assert!(!builder1.color);
 
             let &mut builder2 = builder1.set_color(true);
-            assert_eq!(builder1.color, true);
+            assert!(builder1.color);
             assert_eq!(builder1, builder2);
         }
 
@@ -452,26 +2230,44 @@ mod tests {
 
         #[test]
         fn test_simple_format() {
-            let expected =
-                print_simple_results(*PUZZLE, &VALUE_SOLUTIONS, false);
+            let expected = print_simple_results(
+                *PUZZLE,
+                &VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
             let result = OutputFormat::default()
                 .set_color(false)
                 .set_style(StyleFormat::Simple)
-                .format(*PUZZLE, &VALUE_SOLUTIONS);
+                .format(&result());
 
             assert_eq!(expected, result);
         }
 
         #[test]
         fn test_tabulated_format() {
-            let expected =
-                print_tabulated_results(*PUZZLE, &VALUE_SOLUTIONS, false);
+            let expected = print_tabulated_results(
+                *PUZZLE,
+                &VALUE_SOLUTIONS,
+                &NO_VERDICTS,
+                false,
+            );
             let result = OutputFormat::default()
                 .set_color(false)
                 .set_style(StyleFormat::Tabulated)
-                .format(*PUZZLE, &VALUE_SOLUTIONS);
+                .format(&result());
 
             assert_eq!(expected, result);
         }
+
+        #[test]
+        fn test_json_format() {
+            let formatted = OutputFormat::default()
+                .set_color(true)
+                .set_style(StyleFormat::Json)
+                .format(&result());
+
+            assert_eq!(formatted, print_json_result(&result()));
+        }
     }
 }
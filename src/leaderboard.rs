@@ -0,0 +1,426 @@
+//! Private leaderboards, as served by AoC in JSON.
+//!
+//! Besides the rankings AoC shows, it can break the local score down by day,
+//! show how long each member took to get the second star after the first
+//! one, and list every star in the order they were obtained.
+
+use crate::Part;
+use chrono::{TimeZone, Utc};
+use clap::ValueEnum;
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use tabled::{
+    builder::Builder,
+    settings::{object::Columns, Alignment, Style},
+};
+
+/// Controls which view of the leaderboard is displayed
+#[derive(PartialEq, Eq, Clone, Copy, Debug, ValueEnum, Default)]
+pub enum LeaderboardView {
+    /// Members ordered by local score
+    #[default]
+    Rankings,
+    /// Local score earned by each member every day
+    LocalScore,
+    /// Time between the first and the second star of every day
+    DeltaTime,
+    /// Every star, in the order they were obtained
+    Timeline,
+}
+
+/// When a member got a star
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+/// A member of a private leaderboard
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub last_star_ts: i64,
+    /// Stars obtained, indexed by day and part
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u8, Star>>,
+}
+
+impl Member {
+    /// Returns the name of the member, as AoC shows the anonymous ones
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Returns when the member got the star of the given part
+    pub fn star_ts(&self, day: u32, part: Part) -> Option<i64> {
+        let star = self.completion_day_level.get(&day)?.get(&part.level())?;
+
+        Some(star.get_star_ts)
+    }
+}
+
+/// A private leaderboard of a year
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    /// Parses the JSON AoC serves for private leaderboards
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the members from the highest local score to the lowest.
+    /// Ties go to the one with more stars, then to the first to get them.
+    pub fn rankings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star_ts,
+                member.id,
+            )
+        });
+
+        members
+    }
+
+    /// Returns the last day any member got a star
+    pub fn days(&self) -> u32 {
+        self.members
+            .values()
+            .filter_map(|member| {
+                member.completion_day_level.keys().next_back().copied()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the points earned by each member every day, indexed by
+    /// member id. The first member to get a star earns as many points as
+    /// members there are, the next one a point less, and so on.
+    pub fn local_scores(&self) -> BTreeMap<u64, Vec<u64>> {
+        let days = self.days();
+        let mut scores: BTreeMap<u64, Vec<u64>> = self
+            .members
+            .values()
+            .map(|member| (member.id, vec![0; days as usize]))
+            .collect();
+
+        for day in 1..=days {
+            for part in [Part::P1, Part::P2] {
+                let mut stars: Vec<(i64, u64)> = self
+                    .members
+                    .values()
+                    .filter_map(|member| {
+                        Some((member.star_ts(day, part)?, member.id))
+                    })
+                    .collect();
+                stars.sort();
+
+                for (position, (_, id)) in stars.iter().enumerate() {
+                    let points = (self.members.len() - position) as u64;
+                    scores.get_mut(id).unwrap()[day as usize - 1] += points;
+                }
+            }
+        }
+
+        scores
+    }
+
+    /// Returns every star as `(timestamp, day, part, member)`, from the
+    /// first obtained to the last one
+    pub fn timeline(&self) -> Vec<(i64, u32, Part, &Member)> {
+        let mut stars = Vec::new();
+
+        for member in self.members.values() {
+            for day in member.completion_day_level.keys() {
+                for part in [Part::P1, Part::P2] {
+                    if let Some(ts) = member.star_ts(*day, part) {
+                        stars.push((ts, *day, part, member));
+                    }
+                }
+            }
+        }
+        stars.sort_by_key(|(ts, day, part, member)| {
+            (*ts, *day, part.level(), member.id)
+        });
+
+        stars
+    }
+
+    /// Returns when the puzzle of the given day was unlocked: midnight in
+    /// UTC-5
+    fn unlock_ts(&self, day: u32) -> Option<i64> {
+        let year = self.event.parse().ok()?;
+        let unlock = Utc.with_ymd_and_hms(year, 12, day, 5, 0, 0).single()?;
+
+        Some(unlock.timestamp())
+    }
+
+    /// Renders the given view of the leaderboard as a table
+    pub fn render(&self, view: LeaderboardView, with_color: bool) -> String {
+        let bold = |text: &str| match with_color {
+            true => text.bold().to_string(),
+            false => text.to_string(),
+        };
+        let days: Vec<String> =
+            (1..=self.days()).map(|day| day.to_string()).collect();
+        let mut builder = Builder::default();
+
+        match view {
+            LeaderboardView::Rankings => {
+                builder
+                    .push_record(["#", "Member", "Score", "Stars"].map(bold));
+
+                for (rank, member) in self.rankings().iter().enumerate() {
+                    builder.push_record([
+                        (rank + 1).to_string(),
+                        member.display_name(),
+                        member.local_score.to_string(),
+                        member.stars.to_string(),
+                    ]);
+                }
+            }
+            LeaderboardView::LocalScore => {
+                let scores = self.local_scores();
+                builder.push_record(
+                    std::iter::once("Member")
+                        .chain(days.iter().map(String::as_str))
+                        .chain(std::iter::once("Total"))
+                        .map(bold),
+                );
+
+                for member in self.rankings() {
+                    let points = &scores[&member.id];
+                    let mut record = vec![member.display_name()];
+                    record.extend(points.iter().map(u64::to_string));
+                    record.push(points.iter().sum::<u64>().to_string());
+                    builder.push_record(record);
+                }
+            }
+            LeaderboardView::DeltaTime => {
+                builder.push_record(
+                    std::iter::once("Member")
+                        .chain(days.iter().map(String::as_str))
+                        .map(bold),
+                );
+
+                for member in self.rankings() {
+                    let mut record = vec![member.display_name()];
+                    record.extend((1..=self.days()).map(|day| {
+                        match (
+                            member.star_ts(day, Part::P1),
+                            member.star_ts(day, Part::P2),
+                        ) {
+                            (Some(p1), Some(p2)) => format_elapsed(p2 - p1),
+                            _ => String::from("-"),
+                        }
+                    }));
+                    builder.push_record(record);
+                }
+            }
+            LeaderboardView::Timeline => {
+                builder.push_record(
+                    ["Day", "Part", "Member", "Since unlock"].map(bold),
+                );
+
+                for (ts, day, part, member) in self.timeline() {
+                    let elapsed = match self.unlock_ts(day) {
+                        Some(unlock) => format_elapsed(ts - unlock),
+                        None => String::from("-"),
+                    };
+
+                    builder.push_record([
+                        day.to_string(),
+                        part.to_string(),
+                        member.display_name(),
+                        elapsed,
+                    ]);
+                }
+            }
+        }
+
+        let numbers = match view {
+            LeaderboardView::Rankings => Columns::new(2..),
+            LeaderboardView::LocalScore | LeaderboardView::DeltaTime => {
+                Columns::new(1..)
+            }
+            LeaderboardView::Timeline => Columns::new(3..),
+        };
+
+        let mut table = builder.build();
+        table
+            .with(Style::rounded())
+            .modify(numbers, Alignment::right());
+
+        table.to_string()
+    }
+}
+
+/// Formats a number of seconds as `H:MM:SS`
+fn format_elapsed(seconds: i64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/test_data/leaderboard.json");
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::from_json(FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_rankings() {
+        let names: Vec<String> = leaderboard()
+            .rankings()
+            .iter()
+            .map(|member| member.display_name())
+            .collect();
+
+        assert_eq!(names, ["Alice", "(anonymous user #2)", "Bob", "Carol"]);
+    }
+
+    #[test]
+    fn test_local_scores_match_aoc() {
+        let leaderboard = leaderboard();
+        let scores = leaderboard.local_scores();
+
+        assert_eq!(scores[&1], [7, 7]);
+        for member in leaderboard.rankings() {
+            assert_eq!(
+                scores[&member.id].iter().sum::<u64>(),
+                member.local_score
+            );
+        }
+    }
+
+    #[test]
+    fn test_timeline_order() {
+        let stars: Vec<(u32, Part, u64)> = leaderboard()
+            .timeline()
+            .iter()
+            .map(|(_, day, part, member)| (*day, *part, member.id))
+            .collect();
+
+        assert_eq!(
+            stars,
+            [
+                (1, Part::P1, 2),
+                (1, Part::P1, 1),
+                (1, Part::P2, 1),
+                (1, Part::P2, 2),
+                (1, Part::P1, 3),
+                (2, Part::P1, 3),
+                (2, Part::P1, 1),
+                (2, Part::P2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_elapsed() {
+        assert_eq!(format_elapsed(0), "0:00:00");
+        assert_eq!(format_elapsed(3 * 3600 + 62), "3:01:02");
+        assert_eq!(format_elapsed(30 * 3600), "30:00:00");
+    }
+
+    #[test]
+    fn test_malformed_json() {
+        assert!(Leaderboard::from_json("<html></html>").is_err());
+    }
+
+    mod render {
+        use super::*;
+
+        #[test]
+        fn test_rankings() {
+            let expected = "╭───┬─────────────────────┬───────┬───────╮
+│ # │ Member              │ Score │ Stars │
+├───┼─────────────────────┼───────┼───────┤
+│ 1 │ Alice               │    14 │     4 │
+│ 2 │ (anonymous user #2) │     7 │     2 │
+│ 3 │ Bob                 │     6 │     2 │
+│ 4 │ Carol               │     0 │     0 │
+╰───┴─────────────────────┴───────┴───────╯";
+
+            assert_eq!(
+                leaderboard().render(LeaderboardView::Rankings, false),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_local_score() {
+            let expected = "╭─────────────────────┬───┬───┬───────╮
+│ Member              │ 1 │ 2 │ Total │
+├─────────────────────┼───┼───┼───────┤
+│ Alice               │ 7 │ 7 │    14 │
+│ (anonymous user #2) │ 7 │ 0 │     7 │
+│ Bob                 │ 2 │ 4 │     6 │
+│ Carol               │ 0 │ 0 │     0 │
+╰─────────────────────┴───┴───┴───────╯";
+
+            assert_eq!(
+                leaderboard().render(LeaderboardView::LocalScore, false),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_delta_time() {
+            let expected = "╭─────────────────────┬─────────┬─────────╮
+│ Member              │       1 │       2 │
+├─────────────────────┼─────────┼─────────┤
+│ Alice               │ 0:05:00 │ 0:20:00 │
+│ (anonymous user #2) │ 1:00:00 │       - │
+│ Bob                 │       - │       - │
+│ Carol               │       - │       - │
+╰─────────────────────┴─────────┴─────────╯";
+
+            assert_eq!(
+                leaderboard().render(LeaderboardView::DeltaTime, false),
+                expected
+            );
+        }
+
+        #[test]
+        fn test_timeline() {
+            let expected = "╭─────┬──────┬─────────────────────┬──────────────╮
+│ Day │ Part │ Member              │ Since unlock │
+├─────┼──────┼─────────────────────┼──────────────┤
+│ 1   │ P1   │ (anonymous user #2) │      0:03:20 │
+│ 1   │ P1   │ Alice               │      0:05:00 │
+│ 1   │ P2   │ Alice               │      0:10:00 │
+│ 1   │ P2   │ (anonymous user #2) │      1:03:20 │
+│ 1   │ P1   │ Bob                 │      3:00:00 │
+│ 2   │ P1   │ Bob                 │      0:05:00 │
+│ 2   │ P1   │ Alice               │      0:10:00 │
+│ 2   │ P2   │ Alice               │      0:30:00 │
+╰─────┴──────┴─────────────────────┴──────────────╯";
+
+            assert_eq!(
+                leaderboard().render(LeaderboardView::Timeline, false),
+                expected
+            );
+        }
+    }
+}
@@ -1,5 +1,11 @@
+pub mod answers;
+pub mod calendar;
+pub mod config;
 pub mod constants;
+pub mod description;
 pub mod formatter;
+pub mod leaderboard;
+pub mod progress;
 pub mod providers;
 pub mod services;
 pub mod solvers;
@@ -35,31 +41,96 @@ pub enum Execute {
     P2,
 }
 
+/// One of the two parts of a puzzle
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Display, ValueEnum)]
+pub enum Part {
+    #[value(alias = "1")]
+    P1,
+    #[value(alias = "2")]
+    P2,
+}
+
+impl Part {
+    /// Returns the part number, as AoC calls it: 1 or 2
+    pub fn level(&self) -> u8 {
+        match self {
+            Part::P1 => 1,
+            Part::P2 => 2,
+        }
+    }
+
+    /// Returns the [`Execute`] option running only this part
+    pub fn execute(&self) -> Execute {
+        match self {
+            Part::P1 => Execute::P1,
+            Part::P2 => Execute::P2,
+        }
+    }
+}
+
+impl Execute {
+    /// Returns whether part 1 and part 2, in that order, should run
+    pub fn parts(&self) -> [bool; 2] {
+        match self {
+            Execute::ALL => [true, true],
+            Execute::P1 => [true, false],
+            Execute::P2 => [false, true],
+        }
+    }
+}
+
 impl Puzzle {
     /// Creates a new Puzzle input
-    /// It will fail if the year or the day are outside the valid ranges
+    /// It will fail if the year is before the first event, or the day had no
+    /// puzzle, according to the [`calendar`]
     pub fn new(year: u32, day: u32) -> Result<Self, PuzzleError> {
         trace!("Creating new puzzle with year {}, day {}", year, day);
 
-        if !constants::VALID_YEARS.contains(&year) {
+        if year < calendar::FIRST_YEAR {
             return Err(PuzzleError::InvalidYear(format!(
-                "year should be in [{}-{}] range. Current: {year}.",
-                constants::VALID_YEARS.start(),
-                constants::VALID_YEARS.end()
+                "year should be {} or later. Current: {year}.",
+                calendar::FIRST_YEAR
             )));
         }
 
-        if !constants::VALID_DAYS.contains(&day) {
+        let days = calendar::days(year);
+        if !days.contains(&day) {
             return Err(PuzzleError::InvalidDay(format!(
                 "day should be in [{}-{}] range. Current: {day}.",
-                constants::VALID_DAYS.start(),
-                constants::VALID_DAYS.end()
+                days.start(),
+                days.end()
             )));
         }
 
         Ok(Self { year, day })
     }
 
+    /// Creates a new Puzzle input of an event already announced
+    /// It will fail like [`Puzzle::new`], or if the year is after
+    /// `latest_year`, the year of the latest event
+    pub fn released(
+        year: u32,
+        day: u32,
+        latest_year: u32,
+    ) -> Result<Self, PuzzleError> {
+        let years = calendar::years(latest_year);
+        if !years.contains(&year) {
+            return Err(PuzzleError::InvalidYear(format!(
+                "year should be in [{}-{}] range. Current: {year}.",
+                years.start(),
+                years.end()
+            )));
+        }
+
+        Self::new(year, day)
+    }
+
+    /// Returns whether the puzzle only has one part, like the last day of
+    /// every event
+    pub fn is_single_part(&self) -> bool {
+        calendar::is_single_part(self.year, self.day)
+    }
+
     /// Returns the stored year in the puzzle
     pub fn year(&self) -> u32 {
         self.year
@@ -70,3 +141,39 @@ impl Puzzle {
         self.day
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_released_puzzle() {
+        assert_eq!(
+            Puzzle::released(2024, 25, 2024),
+            Ok(Puzzle {
+                year: 2024,
+                day: 25
+            })
+        );
+        assert_eq!(
+            Puzzle::released(2025, 1, 2024),
+            Err(PuzzleError::InvalidYear(
+                "year should be in [2015-2024] range. Current: 2025.".into()
+            ))
+        );
+        assert!(matches!(
+            Puzzle::released(2024, 26, 2024),
+            Err(PuzzleError::InvalidDay(_))
+        ));
+    }
+
+    #[test]
+    fn test_year_before_first_event() {
+        assert_eq!(
+            Puzzle::new(2014, 1),
+            Err(PuzzleError::InvalidYear(
+                "year should be 2015 or later. Current: 2014.".into()
+            ))
+        );
+    }
+}
@@ -1,38 +1,78 @@
 use aoc::{
-    formatter::{self, StyleFormat},
+    config,
+    constants::{
+        AOC_ANSWERS_DIR, AOC_CONFIG, AOC_INPUTS_DIR, AOC_TIMEZONE,
+        AOC_USER_AGENT, DEFAULT_ANSWERS_DIR, DEFAULT_INPUTS_DIR,
+        UNLOCK_DELAY_MS,
+    },
+    description::{DescriptionFormat, Example},
+    formatter::{self, BenchResult, PuzzleResult, StyleFormat},
+    leaderboard::LeaderboardView,
+    progress::{self, YearProgress},
+    providers::http::HTTPError,
     services::{
-        DateAdapter, DateService, FSService, HTTPAdapter, HTTPService,
-        LocalFSAdapter,
+        date, fs, http::SubmitOutcome, DateAdapter, DateService, FSService,
+        HTTPAdapter, HTTPService, LocalFSAdapter,
     },
-    solvers, Execute, Puzzle,
+    solvers::{self, Solution},
+    Execute, Part, Puzzle,
+};
+use clap::{
+    parser::ValueSource, ArgMatches, Args, CommandFactory, FromArgMatches,
+    Parser, Subcommand,
 };
-use clap::{Args, Parser, Subcommand};
 use human_panic::setup_panic;
 use log::{info, trace};
 use std::{
+    collections::{btree_map::Entry, BTreeMap},
     error::Error,
-    path::PathBuf,
+    io::{self, IsTerminal, Read},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
     sync::{LazyLock, RwLock},
-    time::Instant,
+    thread,
+    time::Duration,
 };
 
 static DATE_SERVICE: LazyLock<DateService<DateAdapter>> =
-    LazyLock::new(|| DateService::default());
+    LazyLock::new(|| match std::env::var(AOC_TIMEZONE) {
+        Ok(timezone) => {
+            let timezone = timezone.parse().unwrap_or_else(|e| {
+                eprintln!("Invalid {}: {}", AOC_TIMEZONE, e);
+                std::process::exit(exitcode::CONFIG);
+            });
+
+            DateService::new(DateAdapter::new(timezone))
+        }
+        Err(_) => DateService::default(),
+    });
 
 static FS_SERVICE: LazyLock<FSService<LocalFSAdapter>> =
-    LazyLock::new(|| FSService::default());
+    LazyLock::new(FSService::default);
+
+static HTTP_SERVICE: LazyLock<
+    RwLock<HTTPService<HTTPAdapter, LocalFSAdapter>>,
+> = LazyLock::new(|| RwLock::new(HTTPService::default()));
 
-static HTTP_SERVICE: LazyLock<RwLock<HTTPService<HTTPAdapter>>> =
-    LazyLock::new(|| RwLock::new(HTTPService::default()));
+/// Where the puzzle input is read from
+#[derive(Clone, PartialEq, Debug)]
+enum PuzzleInput {
+    Stdin,
+    File(PathBuf),
+}
+
+fn parse_puzzle_input(data: &str) -> Result<PuzzleInput, String> {
+    if data == "-" {
+        return Ok(PuzzleInput::Stdin);
+    }
 
-fn validate_is_file(data: &str) -> Result<PathBuf, String> {
     let path = PathBuf::from(data);
 
     if !path.is_file() {
         return Err(format!("{} is not a file", path.to_str().unwrap()));
     }
 
-    Ok(path)
+    Ok(PuzzleInput::File(path))
 }
 
 #[derive(Parser, Debug)]
@@ -40,14 +80,34 @@ fn validate_is_file(data: &str) -> Result<PathBuf, String> {
 struct Cli {
     /// Selected year.
     /// Defaults to current year on December, last year otherwise.
+    /// Dates are in AoC's timezone, America/New_York, unless AOC_TIMEZONE
+    /// is set.
     #[arg(long, short, default_value_t = DATE_SERVICE.default_year(), global = true)]
     year: u32,
 
     /// Selected day.
-    /// Defaults to current day on December between 1-25, 1 otherwise.
+    /// Defaults to current day on December during the event, 1 otherwise.
     #[arg(long, short, default_value_t = DATE_SERVICE.default_day(), global = true)]
     day: u32,
 
+    /// Directory where the puzzle inputs are cached
+    #[arg(long, env = AOC_INPUTS_DIR, default_value = DEFAULT_INPUTS_DIR, global = true)]
+    inputs_dir: PathBuf,
+
+    /// Directory where the submitted answers are recorded
+    #[arg(long, env = AOC_ANSWERS_DIR, default_value = DEFAULT_ANSWERS_DIR, global = true)]
+    answers_dir: PathBuf,
+
+    /// User-Agent sent to AoC. Defaults to the name of the crate and its
+    /// author
+    #[arg(long, env = AOC_USER_AGENT, global = true)]
+    user_agent: Option<String>,
+
+    /// User configuration, where `aoc auth set` stores the session cookie.
+    /// Defaults to aoc/config.toml in the user config directory
+    #[arg(long, env = AOC_CONFIG, global = true)]
+    config: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -55,23 +115,114 @@ struct Cli {
 enum Commands {
     /// Downloads the specified puzzle input from AoC
     Download(DownloadArgs),
+    /// Shows the description of the specified puzzle, and saves its examples
+    Read(ReadArgs),
     /// Solve the specified puzzle
     Solve(SolveArgs),
-    /// Generate the boilerplate code to solve the aforementioned challenge
+    /// Benchmark the specified puzzle with its cached input, reporting the
+    /// time spent parsing and solving each part over many runs
+    Bench(BenchArgs),
+    /// Submit the answer of a part of the specified puzzle to AoC
+    Submit(SubmitArgs),
+    /// Check every implemented puzzle against its confirmed answers
+    Verify(VerifyArgs),
+    /// Shows the stars earned every year, from the confirmed answers
+    Progress(ProgressArgs),
+    /// Shows a private leaderboard of the selected year
+    Leaderboard(LeaderboardArgs),
+    /// Saves the examples of the specified puzzle, and prints the tests
+    /// checking their answers
+    Examples(ExamplesArgs),
+    /// Manages the AoC session cookie stored in the user configuration
+    #[command(subcommand)]
+    Auth(AuthCommands),
+    /// Generate the boilerplate code to solve the aforementioned challenge.
+    /// Tests for the examples are added when the description is cached
     Generate,
+    /// Rename the cached inputs to the yYYYY/dayDD.txt naming scheme
+    MigrateInputs,
 }
 
 #[derive(Args, PartialEq, Debug)]
 struct DownloadArgs {
     /// AOC_COOKIE required to download the puzzle input. Can be set in an envvar.
+    /// Defaults to the one stored with `aoc auth set`
     #[arg(long, short, env, hide_env_values = true)]
-    aoc_cookie: String,
+    aoc_cookie: Option<String>,
+
+    /// Downloads the input again, even if it is already cached
+    #[arg(long, short, default_value_t = false)]
+    force: bool,
+
+    /// Waits until the puzzle unlocks, showing a countdown, and downloads
+    /// its input right after
+    #[arg(long, short, default_value_t = false)]
+    wait: bool,
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct ReadArgs {
+    /// Only shows the description of this part. Defaults to every unlocked
+    /// part
+    #[arg(long, short, value_enum)]
+    part: Option<Part>,
+
+    /// Control how the description is displayed
+    #[arg(long, value_enum, default_value_t = DescriptionFormat::Text)]
+    format: DescriptionFormat,
+
+    /// Downloads the description again and overwrites the saved examples
+    #[arg(long, short, default_value_t = false)]
+    force: bool,
+
+    /// AOC_COOKIE required to download the description. Can be set in an envvar.
+    /// Defaults to the one stored with `aoc auth set`
+    #[arg(long, short, env, hide_env_values = true)]
+    aoc_cookie: Option<String>,
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct ExamplesArgs {
+    /// Puzzle page to read the examples from. Defaults to the cached
+    /// description, downloading it when it is missing
+    #[arg(long)]
+    html: Option<PathBuf>,
+
+    /// Overwrites the saved examples
+    #[arg(long, short, default_value_t = false)]
+    force: bool,
+
+    /// AOC_COOKIE required to download the description. Can be set in an envvar.
+    /// Defaults to the one stored with `aoc auth set`
+    #[arg(long, short, env, hide_env_values = true)]
+    aoc_cookie: Option<String>,
+}
+
+#[derive(Subcommand, Debug, PartialEq)]
+enum AuthCommands {
+    /// Stores the session cookie, only readable by the current user
+    Set {
+        /// Value of the `session` cookie of adventofcode.com. Read from
+        /// the standard input when missing
+        cookie: Option<String>,
+    },
+    /// Checks the session cookie with AoC, and shows the logged in user
+    Check {
+        /// AOC_COOKIE to check. Can be set in an envvar. Defaults to the one
+        /// stored with `aoc auth set`
+        #[arg(long, short, env, hide_env_values = true)]
+        aoc_cookie: Option<String>,
+    },
+    /// Removes the stored session cookie
+    Clear,
 }
 
 #[derive(Args, PartialEq, Debug)]
 struct SolveArgs {
-    #[arg(value_parser = validate_is_file)]
-    puzzle_input: PathBuf,
+    /// Path to the puzzle input, or `-` to read it from stdin.
+    /// Defaults to the cached input of the puzzle
+    #[arg(value_parser = parse_puzzle_input)]
+    puzzle_input: Option<PuzzleInput>,
 
     #[arg(value_enum, default_value_t = Execute::ALL)]
     execute: Execute,
@@ -88,13 +239,327 @@ struct SolveArgs {
     /// Removes the color from the ouput [default: false]
     #[arg(long, default_value_t = false)]
     no_color: bool,
+
+    /// Gives up on the puzzle after this many seconds
+    #[arg(long)]
+    timeout: Option<u64>,
+
+    /// Solves every implemented puzzle with its cached input.
+    /// Only the puzzles of the selected year when --year is given
+    #[arg(long, default_value_t = false, conflicts_with_all = ["puzzle_input", "day"])]
+    all: bool,
+
+    /// Number of puzzles solved at the same time with --all.
+    /// Defaults to the number of available cores
+    #[arg(long, short, requires = "all")]
+    jobs: Option<NonZeroUsize>,
+
+    /// Solves part 1 and part 2 of each puzzle in parallel with --all
+    #[arg(long, default_value_t = false, requires = "all")]
+    split_parts: bool,
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct BenchArgs {
+    #[arg(value_enum, default_value_t = Execute::ALL)]
+    execute: Execute,
+
+    /// Number of measured runs
+    #[arg(long, short = 'n', default_value = "10")]
+    runs: NonZeroUsize,
+
+    /// Number of runs before measuring, to warm up the caches
+    #[arg(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Control how the results are displayed
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t = StyleFormat::Tabulated,
+    )]
+    style: StyleFormat,
+
+    /// Removes the color from the ouput [default: false]
+    #[arg(long, default_value_t = false)]
+    no_color: bool,
+
+    /// Benchmarks every implemented puzzle, one after the other.
+    /// Only the puzzles of the selected year when --year is given
+    #[arg(long, default_value_t = false, conflicts_with = "day")]
+    all: bool,
+}
+
+/// Returns whether the year was explicitly set in the command line, as
+/// opposed to its default value
+fn year_given(matches: &ArgMatches) -> bool {
+    let source = match matches.subcommand() {
+        Some((_, sub_matches)) => sub_matches.value_source("year"),
+        None => matches.value_source("year"),
+    };
+
+    source == Some(ValueSource::CommandLine)
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct VerifyArgs {
+    /// Control how the results are displayed
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value_t = StyleFormat::Tabulated,
+    )]
+    style: StyleFormat,
+
+    /// Removes the color from the ouput [default: false]
+    #[arg(long, default_value_t = false)]
+    no_color: bool,
+
+    /// Number of puzzles solved at the same time.
+    /// Defaults to the number of available cores
+    #[arg(long, short)]
+    jobs: Option<NonZeroUsize>,
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct ProgressArgs {
+    /// Rewrites the progress section of this Markdown file instead, between
+    /// the `aoc-progress:start` and `aoc-progress:end` comments
+    #[arg(long, num_args = 0..=1, default_missing_value = "README.md")]
+    readme: Option<PathBuf>,
+
+    /// Removes the color from the ouput [default: false]
+    #[arg(long, default_value_t = false)]
+    no_color: bool,
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct LeaderboardArgs {
+    /// Id of the private leaderboard, as shown in its URL
+    id: u64,
+
+    /// Control which view of the leaderboard is displayed
+    #[arg(long, value_enum, default_value_t = LeaderboardView::Rankings)]
+    view: LeaderboardView,
+
+    /// Removes the color from the ouput [default: false]
+    #[arg(long, default_value_t = false)]
+    no_color: bool,
+
+    /// Downloads the leaderboard again, even if it was downloaded less than
+    /// 15 minutes ago
+    #[arg(long, short, default_value_t = false)]
+    force: bool,
+
+    /// AOC_COOKIE required to download the leaderboard. Can be set in an envvar.
+    /// Defaults to the one stored with `aoc auth set`
+    #[arg(long, short, env, hide_env_values = true)]
+    aoc_cookie: Option<String>,
+}
+
+/// Returns every implemented puzzle, or only the ones of the given year
+fn selected_puzzles(year: Option<u32>) -> Vec<Puzzle> {
+    solvers::implemented_puzzles()
+        .into_iter()
+        .filter(|puzzle| year.is_none_or(|year| puzzle.year() == year))
+        .collect()
+}
+
+/// Solves the puzzles with their cached inputs, comparing the solutions
+/// with the confirmed answers
+fn solve_batch(
+    puzzles: &[Puzzle],
+    executor: &solvers::Executor,
+    execute: Execute,
+    inputs_dir: &Path,
+    answers_dir: &Path,
+) -> Result<Vec<PuzzleResult>, Box<dyn Error>> {
+    let jobs: Vec<_> = puzzles
+        .iter()
+        .map(|puzzle| solvers::Job {
+            solver: solvers::get_solver(puzzle)
+                .expect("implemented puzzles have a solver"),
+            input_path: fs::input_path(inputs_dir, puzzle),
+        })
+        .collect();
+    let reports = executor.run(&jobs, execute);
+
+    let mut ledgers = BTreeMap::new();
+    let mut results = Vec::new();
+    for (puzzle, report) in puzzles.iter().zip(reports) {
+        let report = report.for_puzzle(puzzle);
+        let ledger = match ledgers.entry(puzzle.year()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry
+                .insert(FS_SERVICE.load_ledger(answers_dir, puzzle.year())?),
+        };
+        let verdicts = ledger.verdicts(puzzle.day(), &report.solutions);
+
+        results.push(PuzzleResult {
+            puzzle: *puzzle,
+            report,
+            verdicts,
+        });
+    }
+
+    Ok(results)
+}
+
+#[derive(Args, PartialEq, Debug)]
+struct SubmitArgs {
+    #[arg(value_enum, default_value_t = Part::P1)]
+    part: Part,
+
+    /// Answer to submit. Defaults to solving the part with the cached input
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// AOC_COOKIE required to submit the answer. Can be set in an envvar.
+    /// Defaults to the one stored with `aoc auth set`
+    #[arg(long, short, env, hide_env_values = true)]
+    aoc_cookie: Option<String>,
+}
+
+/// Returns the solver of the puzzle, exiting when there is none
+fn solver_for(puzzle: &Puzzle) -> solvers::SolveFn {
+    solvers::get_solver(puzzle).unwrap_or_else(|| {
+        if !solvers::implemented_years().contains(&puzzle.year()) {
+            eprintln!("{} not implemented", puzzle.year());
+            std::process::exit(exitcode::DATAERR);
+        }
+
+        eprintln!("Day {:02} is not implemented!", puzzle.day());
+        std::process::exit(exitcode::UNAVAILABLE)
+    })
+}
+
+/// Returns the path of the cached input of the puzzle, exiting when it
+/// has not been downloaded yet
+fn cached_input(inputs_dir: &Path, puzzle: &Puzzle) -> PathBuf {
+    let path = fs::input_path(inputs_dir, puzzle);
+
+    if !path.is_file() {
+        eprintln!("No input found at {}", path.display());
+        eprintln!("Download it first, or pass its path.");
+        std::process::exit(exitcode::NOINPUT);
+    }
+
+    path
+}
+
+/// Returns the path of the user configuration, exiting when there is none
+fn config_path(config: Option<PathBuf>) -> PathBuf {
+    config
+        .or_else(config::default_config_path)
+        .unwrap_or_else(|| {
+            eprintln!("No config directory found, pass --config");
+            std::process::exit(exitcode::CONFIG);
+        })
+}
+
+/// Returns the cookie given in the command line or the environment, and
+/// otherwise the one stored in the user configuration
+fn stored_cookie(
+    cookie: Option<String>,
+    config: &Path,
+) -> io::Result<Option<String>> {
+    match cookie {
+        Some(cookie) => Ok(Some(cookie)),
+        None => Ok(FS_SERVICE.load_config(config)?.session),
+    }
+}
+
+/// Sets the cookie of the HTTP service, exiting when there is none
+fn resolve_cookie(
+    cookie: Option<String>,
+    config: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let Some(cookie) = stored_cookie(cookie, config)? else {
+        eprintln!("No AoC session cookie found.");
+        eprintln!(
+            "Pass --aoc-cookie, set AOC_COOKIE, or store it with `aoc auth set`."
+        );
+        std::process::exit(exitcode::CONFIG);
+    };
+
+    HTTP_SERVICE.write()?.set_cookie(cookie);
+
+    Ok(())
+}
+
+/// Reads the cookie to store from the standard input, prompting for it when
+/// it is a terminal
+fn read_cookie() -> io::Result<String> {
+    let mut stdin = io::stdin();
+
+    if stdin.is_terminal() {
+        eprintln!("Paste the value of the session cookie and press Enter:");
+        let mut line = String::new();
+        stdin.read_line(&mut line)?;
+        return Ok(line);
+    }
+
+    let mut cookie = String::new();
+    stdin.read_to_string(&mut cookie)?;
+
+    Ok(cookie)
+}
+
+/// Waits until the puzzle unlocks, showing the time left, and then a bit
+/// more so AoC is not hit by every tool at the same instant
+fn wait_for_unlock(puzzle: &Puzzle) {
+    let interactive = io::stderr().is_terminal();
+
+    DATE_SERVICE.wait_for_unlock(puzzle, |remaining, tick| {
+        if interactive {
+            eprint!(
+                "\r{}/{:02} unlocks in {}",
+                puzzle.year(),
+                puzzle.day(),
+                date::format_countdown(remaining)
+            );
+        }
+
+        thread::sleep(tick);
+    });
+
+    if interactive {
+        eprintln!();
+    }
+
+    let delay = Duration::from_millis(fastrand::u64(UNLOCK_DELAY_MS));
+    info!("Waiting {:?} before downloading...", delay);
+    thread::sleep(delay);
+}
+
+/// Saves the examples of the puzzle, reporting the written files
+fn save_examples(
+    inputs_dir: &Path,
+    puzzle: &Puzzle,
+    examples: &[Example],
+    force: bool,
+) -> io::Result<()> {
+    let inputs: Vec<String> = examples
+        .iter()
+        .map(|example| example.input.clone())
+        .collect();
+
+    for path in FS_SERVICE.save_examples(inputs_dir, puzzle, &inputs, force)? {
+        eprintln!("Example saved to {}", path.display());
+    }
+
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     setup_panic!();
+    solvers::quiet_solver_panics();
     pretty_env_logger::init();
 
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches)?;
 
     info!("Application started...");
 
@@ -103,92 +568,548 @@ fn main() -> Result<(), Box<dyn Error>> {
         cli.year,
         cli.day
     );
-    let puzzle = aoc::Puzzle::new(cli.year, cli.day)?;
+    if let Some(user_agent) = cli.user_agent {
+        HTTP_SERVICE.write()?.set_user_agent(user_agent);
+    }
+
+    // Only the commands working on a single puzzle require it to be valid
+    let puzzle =
+        aoc::Puzzle::released(cli.year, cli.day, DATE_SERVICE.latest_year());
 
     match cli.command {
         Commands::Download(args) => {
             trace!("Download command executing...");
-            {
-                HTTP_SERVICE.write()?.set_cookie(args.aoc_cookie);
+            let puzzle = puzzle?;
+
+            let cached = match args.force {
+                true => None,
+                false => HTTP_SERVICE
+                    .read()?
+                    .cached_input(&puzzle, &cli.inputs_dir)?,
+            };
+            if let Some(puzzle_data) = cached {
+                print!("{}", puzzle_data);
+                return Ok(());
             }
 
-            let puzzle_data = HTTP_SERVICE.read()?.download_input(&puzzle)?;
+            resolve_cookie(args.aoc_cookie, &config_path(cli.config))?;
+
+            if let Some(remaining) = DATE_SERVICE.time_until_unlock(&puzzle) {
+                if !args.wait {
+                    eprintln!(
+                        "{}/{:02} is not unlocked yet, it unlocks at {} (in {})",
+                        puzzle.year(),
+                        puzzle.day(),
+                        DATE_SERVICE.unlock_time(&puzzle),
+                        date::format_countdown(remaining)
+                    );
+                    eprintln!(
+                        "Use --wait to download it as soon as it unlocks."
+                    );
+                    std::process::exit(exitcode::UNAVAILABLE);
+                }
+
+                wait_for_unlock(&puzzle);
+            }
+
+            let puzzle_data = HTTP_SERVICE.read()?.download_input(
+                &puzzle,
+                &cli.inputs_dir,
+                true,
+            )?;
 
             print!("{}", puzzle_data);
         }
+        Commands::Read(args) => {
+            trace!("Read command executing...");
+            let puzzle = puzzle?;
+
+            let cached = match args.force {
+                true => None,
+                false => HTTP_SERVICE.read()?.cached_description(
+                    &puzzle,
+                    args.part,
+                    &cli.inputs_dir,
+                )?,
+            };
+            let description = match cached {
+                Some(description) => description,
+                None => {
+                    resolve_cookie(args.aoc_cookie, &config_path(cli.config))?;
+
+                    HTTP_SERVICE.read()?.download_description(
+                        &puzzle,
+                        args.part,
+                        &cli.inputs_dir,
+                        true,
+                    )?
+                }
+            };
+
+            let Some(text) = description.render(args.part, args.format) else {
+                eprintln!(
+                    "{} is not unlocked yet!",
+                    args.part.unwrap_or(Part::P2)
+                );
+                std::process::exit(exitcode::UNAVAILABLE);
+            };
+
+            save_examples(
+                &cli.inputs_dir,
+                &puzzle,
+                &description.examples(),
+                args.force,
+            )?;
+
+            print!("{}", text);
+        }
+        Commands::Examples(args) => {
+            trace!("Examples command executing...");
+            let puzzle = puzzle?;
+
+            let description = match &args.html {
+                Some(path) => {
+                    FS_SERVICE.load_description(path)?.unwrap_or_else(|| {
+                        eprintln!("{} does not exist", path.display());
+                        std::process::exit(exitcode::NOINPUT);
+                    })
+                }
+                None => {
+                    let config = config_path(cli.config);
+                    if let Some(cookie) =
+                        stored_cookie(args.aoc_cookie, &config)?
+                    {
+                        HTTP_SERVICE.write()?.set_cookie(cookie);
+                    }
+
+                    HTTP_SERVICE.read()?.download_description(
+                        &puzzle,
+                        None,
+                        &cli.inputs_dir,
+                        false,
+                    )?
+                }
+            };
+
+            let examples = description.examples();
+            save_examples(&cli.inputs_dir, &puzzle, &examples, args.force)?;
+
+            let tests =
+                fs::render_example_tests(&cli.inputs_dir, &puzzle, &examples);
+            if tests.is_empty() {
+                eprintln!("No answer found for the examples");
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            print!("{}", tests);
+        }
+        Commands::Solve(args) if args.all => {
+            trace!("Solve command executing for all the puzzles...");
+            let year = year_given(&matches).then_some(cli.year);
+
+            let mut executor = solvers::Executor::default();
+            if let Some(jobs) = args.jobs {
+                executor.set_workers(jobs);
+            }
+            executor
+                .set_split_parts(args.split_parts)
+                .set_timeout(args.timeout.map(Duration::from_secs));
+
+            let results = solve_batch(
+                &selected_puzzles(year),
+                &executor,
+                args.execute,
+                &cli.inputs_dir,
+                &cli.answers_dir,
+            )?;
+
+            if results.is_empty() {
+                eprintln!("{} not implemented", cli.year);
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            let result = formatter::new()
+                .set_style(args.style)
+                .set_color(!args.no_color)
+                .format_batch(&results);
+
+            println!("{}", result);
+
+            if let Some(err) = results
+                .iter()
+                .find_map(|result| result.report.first_error())
+            {
+                std::process::exit(err.exit_code());
+            }
+        }
         Commands::Solve(args) => {
+            let puzzle = puzzle?;
             trace!("Solve command executing for year {}...", puzzle.year());
 
-            let start = Instant::now();
-            let solutions = match puzzle.year() {
-                2023 => {
-                    run_y2023_solver(puzzle, args.execute, &args.puzzle_input)
+            let solver = solver_for(&puzzle);
+            // Only the cached input is the one the confirmed answers belong to
+            let is_cached_input = args.puzzle_input.is_none();
+            let puzzle_input = args.puzzle_input.unwrap_or_else(|| {
+                PuzzleInput::File(cached_input(&cli.inputs_dir, &puzzle))
+            });
+
+            let timeout = args.timeout.map(Duration::from_secs);
+            let report = match &puzzle_input {
+                PuzzleInput::Stdin => solvers::solve_reader(
+                    solver,
+                    args.execute,
+                    io::stdin(),
+                    timeout,
+                ),
+                PuzzleInput::File(path) => {
+                    solvers::solve_file(solver, args.execute, path, timeout)
+                }
+            }
+            .for_puzzle(&puzzle);
+
+            let verdicts = match is_cached_input {
+                true => FS_SERVICE
+                    .load_ledger(&cli.answers_dir, puzzle.year())?
+                    .verdicts(puzzle.day(), &report.solutions),
+                false => [None, None],
+            };
+
+            let result = PuzzleResult {
+                puzzle,
+                report,
+                verdicts,
+            };
+            let format = *formatter::new()
+                .set_style(args.style)
+                .set_color(!args.no_color);
+
+            println!("{}", format.format(&result));
+            if !format.is_machine_readable() {
+                println!("Time elapsed: {:?}", result.report.timings.total());
+            }
+
+            if let Some(err) = result.report.first_error() {
+                std::process::exit(err.exit_code());
+            }
+        }
+        Commands::Bench(args) => {
+            trace!("Bench command executing...");
+            let puzzles: Vec<(Puzzle, PathBuf)> = match args.all {
+                true => {
+                    selected_puzzles(year_given(&matches).then_some(cli.year))
+                        .into_iter()
+                        .map(|puzzle| {
+                            (puzzle, fs::input_path(&cli.inputs_dir, &puzzle))
+                        })
+                        .collect()
                 }
-                2024 => {
-                    run_y2024_solver(puzzle, args.execute, &args.puzzle_input)
+                false => {
+                    let puzzle = puzzle?;
+                    solver_for(&puzzle);
+
+                    vec![(puzzle, cached_input(&cli.inputs_dir, &puzzle))]
                 }
-                _ => {
-                    eprintln!("{} not implemented", cli.year);
-                    std::process::exit(exitcode::DATAERR);
+            };
+
+            if puzzles.is_empty() {
+                eprintln!("{} not implemented", cli.year);
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            let mut bench = solvers::Bench::default();
+            bench.set_runs(args.runs).set_warmup(args.warmup);
+
+            // One puzzle at a time, so they do not slow each other down
+            let results: Vec<BenchResult> = puzzles
+                .into_iter()
+                .map(|(puzzle, input_path)| BenchResult {
+                    puzzle,
+                    report: bench
+                        .run_file(
+                            solver_for(&puzzle),
+                            args.execute,
+                            &input_path,
+                        )
+                        .for_puzzle(&puzzle),
+                })
+                .collect();
+
+            let result = formatter::new()
+                .set_style(args.style)
+                .set_color(!args.no_color)
+                .format_benchmarks(&results);
+
+            println!("{}", result);
+
+            if let Some(err) = results
+                .iter()
+                .find_map(|result| result.report.first_error())
+            {
+                std::process::exit(err.exit_code());
+            }
+        }
+        Commands::Submit(args) => {
+            trace!("Submit command executing...");
+            let puzzle = puzzle?;
+
+            if args.part == Part::P2 && puzzle.is_single_part() {
+                eprintln!(
+                    "Day {:02} only has one part, its second star is given \
+                     for completing every other puzzle",
+                    puzzle.day()
+                );
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            let answer = match args.answer {
+                Some(answer) => answer,
+                None => {
+                    let solver = solver_for(&puzzle);
+                    let path = cached_input(&cli.inputs_dir, &puzzle);
+                    let report = solvers::solve_file(
+                        solver,
+                        args.part.execute(),
+                        &path,
+                        None,
+                    );
+
+                    match &report.solutions[args.part.level() as usize - 1] {
+                        Solution::Value(answer) => answer.to_string(),
+                        Solution::Err(err) => {
+                            eprintln!("Error: {}", err);
+                            std::process::exit(err.exit_code());
+                        }
+                        _ => {
+                            eprintln!("{} is not implemented!", args.part);
+                            std::process::exit(exitcode::UNAVAILABLE);
+                        }
+                    }
                 }
             };
-            let duration = start.elapsed();
+
+            let mut ledger =
+                FS_SERVICE.load_ledger(&cli.answers_dir, puzzle.year())?;
+
+            if let Some(rejection) =
+                ledger.check(puzzle.day(), args.part, &answer)
+            {
+                eprintln!("Not submitting {}: {}", answer, rejection);
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            println!(
+                "Submitting {} for {}/{:02} {}...",
+                answer,
+                puzzle.year(),
+                puzzle.day(),
+                args.part
+            );
+            resolve_cookie(args.aoc_cookie, &config_path(cli.config))?;
+
+            let outcome = HTTP_SERVICE
+                .read()?
+                .submit_answer(&puzzle, args.part, &answer)?;
+
+            println!("{}", outcome);
+
+            if ledger.record(puzzle.day(), args.part, &answer, outcome) {
+                FS_SERVICE.save_ledger(
+                    &cli.answers_dir,
+                    puzzle.year(),
+                    &ledger,
+                )?;
+            }
+
+            if outcome != SubmitOutcome::Right {
+                std::process::exit(outcome.exit_code());
+            }
+        }
+        Commands::Verify(args) => {
+            trace!("Verify command executing...");
+            let year = year_given(&matches).then_some(cli.year);
+
+            let mut executor = solvers::Executor::default();
+            if let Some(jobs) = args.jobs {
+                executor.set_workers(jobs);
+            }
+
+            let results = solve_batch(
+                &selected_puzzles(year),
+                &executor,
+                Execute::ALL,
+                &cli.inputs_dir,
+                &cli.answers_dir,
+            )?;
+
+            if results.is_empty() {
+                eprintln!("{} not implemented", cli.year);
+                std::process::exit(exitcode::DATAERR);
+            }
 
             let result = formatter::new()
                 .set_style(args.style)
                 .set_color(!args.no_color)
-                .format(puzzle, &solutions);
+                .format_verification(&results);
 
             println!("{}", result);
-            println!("Time elapsed: {:?}", duration);
+
+            let [_, failed, _] = formatter::count_verdicts(&results);
+            if failed > 0 {
+                std::process::exit(exitcode::DATAERR);
+            }
         }
-        Commands::Generate => {
-            trace!("Generate command executing...");
+        Commands::Progress(args) => {
+            trace!("Progress command executing...");
+            let year = year_given(&matches).then_some(cli.year);
+
+            let years = solvers::implemented_years()
+                .into_iter()
+                .filter(|y| year.is_none_or(|year| *y == year))
+                .map(|year| {
+                    let days: Vec<u32> = selected_puzzles(Some(year))
+                        .iter()
+                        .map(Puzzle::day)
+                        .collect();
+                    let ledger =
+                        FS_SERVICE.load_ledger(&cli.answers_dir, year)?;
+
+                    Ok(YearProgress::new(year, &days, &ledger))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
 
-            FS_SERVICE.extract_template_for(&puzzle)?;
+            if years.is_empty() {
+                eprintln!("{} not implemented", cli.year);
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            let Some(readme) = args.readme else {
+                println!("{}", progress::render(&years, !args.no_color));
+                return Ok(());
+            };
+
+            let table = progress::render_markdown(&years);
+            if !FS_SERVICE.update_progress_section(&readme, &table)? {
+                eprintln!(
+                    "{} has no progress section. Add these lines where the \
+                     table should go:\n{}\n{}",
+                    readme.display(),
+                    progress::SECTION_START,
+                    progress::SECTION_END
+                );
+                std::process::exit(exitcode::DATAERR);
+            }
+
+            println!("Progress updated in {}", readme.display());
         }
-    }
+        Commands::Leaderboard(args) => {
+            trace!("Leaderboard command executing...");
 
-    Ok(())
-}
+            let cached = match args.force {
+                true => None,
+                false => HTTP_SERVICE.read()?.cached_leaderboard(
+                    cli.year,
+                    args.id,
+                    &cli.inputs_dir,
+                )?,
+            };
+            let leaderboard = match cached {
+                Some(leaderboard) => leaderboard,
+                None => {
+                    resolve_cookie(args.aoc_cookie, &config_path(cli.config))?;
 
-fn run_y2023_solver(
-    puzzle: Puzzle,
-    execute: Execute,
-    input_path: &PathBuf,
-) -> [solvers::Solution; 2] {
-    trace!("Running y2023 solver...");
-
-    let solutions = match puzzle.day() {
-        1 => solvers::y2023::day01::solve(execute, input_path),
-        2 => solvers::y2023::day02::solve(execute, input_path),
-        3 => solvers::y2023::day03::solve(execute, input_path),
-        4 => solvers::y2023::day04::solve(execute, input_path),
-        5 => solvers::y2023::day05::solve(execute, input_path),
-        _ => {
-            eprintln!("Day {:02} is not implemented!", puzzle.day());
-            std::process::exit(exitcode::UNAVAILABLE)
+                    HTTP_SERVICE.read()?.download_leaderboard(
+                        cli.year,
+                        args.id,
+                        &cli.inputs_dir,
+                        true,
+                    )?
+                }
+            };
+
+            println!("{}", leaderboard.render(args.view, !args.no_color));
         }
-    };
+        Commands::Auth(command) => {
+            trace!("Auth command executing...");
+            let config_path = config_path(cli.config);
 
-    solutions
-}
+            match command {
+                AuthCommands::Set { cookie } => {
+                    let cookie = match cookie {
+                        Some(cookie) => cookie,
+                        None => read_cookie()?,
+                    };
+                    let cookie = config::normalize_cookie(&cookie);
+                    if cookie.is_empty() {
+                        eprintln!("The session cookie is empty");
+                        std::process::exit(exitcode::DATAERR);
+                    }
 
-fn run_y2024_solver(
-    puzzle: Puzzle,
-    execute: Execute,
-    input_path: &PathBuf,
-) -> [solvers::Solution; 2] {
-    trace!("Running y2024 solver...");
+                    let mut config = FS_SERVICE.load_config(&config_path)?;
+                    config.session = Some(cookie);
+                    FS_SERVICE.save_config(&config_path, &config)?;
+
+                    println!(
+                        "Session cookie saved to {}",
+                        config_path.display()
+                    );
+                }
+                AuthCommands::Check { aoc_cookie } => {
+                    resolve_cookie(aoc_cookie, &config_path)?;
+
+                    match HTTP_SERVICE.read()?.check_session() {
+                        Ok(user) => println!("Logged in as {}", user),
+                        Err(HTTPError::ExpiredCookie) => {
+                            eprintln!("{}", HTTPError::ExpiredCookie);
+                            std::process::exit(exitcode::NOPERM);
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+                AuthCommands::Clear => {
+                    let mut config = FS_SERVICE.load_config(&config_path)?;
+                    if config.session.take().is_none() {
+                        println!("No session cookie stored");
+                        return Ok(());
+                    }
 
-    let solutions = match puzzle.day() {
-        1 => solvers::y2024::day01::solve(execute, input_path),
-        2 => solvers::y2024::day02::solve(execute, input_path),
-        _ => {
-            eprintln!("Day {:02} is not implemented!", puzzle.day());
-            std::process::exit(exitcode::UNAVAILABLE)
+                    FS_SERVICE.save_config(&config_path, &config)?;
+
+                    println!(
+                        "Session cookie removed from {}",
+                        config_path.display()
+                    );
+                }
+            }
         }
-    };
+        Commands::Generate => {
+            trace!("Generate command executing...");
+            let puzzle = puzzle?;
 
-    solutions
+            let examples = FS_SERVICE
+                .load_description(&fs::description_path(
+                    &cli.inputs_dir,
+                    &puzzle,
+                ))?
+                .map(|description| description.examples())
+                .unwrap_or_default();
+            let tests =
+                fs::render_example_tests(&cli.inputs_dir, &puzzle, &examples);
+
+            FS_SERVICE.extract_template_for(&puzzle, &tests)?;
+            save_examples(&cli.inputs_dir, &puzzle, &examples, false)?;
+            FS_SERVICE.register_solver_for(&puzzle)?;
+        }
+        Commands::MigrateInputs => {
+            trace!("MigrateInputs command executing...");
+
+            let renamed = FS_SERVICE.migrate_inputs(&cli.inputs_dir)?;
+
+            for (from, to) in &renamed {
+                println!("{} -> {}", from.display(), to.display());
+            }
+            println!("{} file(s) renamed", renamed.len());
+        }
+    }
+
+    Ok(())
 }
@@ -0,0 +1,320 @@
+//! Stars earned every year, as a calendar.
+//!
+//! A part earns its star once its answer is confirmed in the ledger, and
+//! the last day of an event earns its second star once every other star of
+//! the year is earned. The calendar is shown in the terminal, or rendered
+//! as a Markdown table to keep a marked section of the README up to date.
+
+use crate::answers::Ledger;
+use crate::{calendar, Part};
+use owo_colors::OwoColorize;
+
+/// Line opening the README section rewritten by [`replace_section`]
+pub const SECTION_START: &str = "<!-- aoc-progress:start -->";
+
+/// Line closing the README section rewritten by [`replace_section`]
+pub const SECTION_END: &str = "<!-- aoc-progress:end -->";
+
+/// The stars of one day of an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: u32,
+    /// Number of stars earned: 0, 1 or 2
+    pub stars: u8,
+    /// Whether there is a solver for the day
+    pub implemented: bool,
+}
+
+/// The stars of every day of an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearProgress {
+    pub year: u32,
+    pub days: Vec<DayProgress>,
+}
+
+impl YearProgress {
+    /// Collects the stars of the year from its ledger, marking the days
+    /// that have a solver
+    pub fn new(year: u32, implemented: &[u32], ledger: &Ledger) -> Self {
+        let confirmed = |day, part| {
+            ledger
+                .part(day, part)
+                .is_some_and(|record| record.confirmed.is_some())
+        };
+
+        let mut days: Vec<DayProgress> = calendar::days(year)
+            .map(|day| DayProgress {
+                day,
+                stars: [Part::P1, Part::P2]
+                    .into_iter()
+                    .filter(|part| confirmed(day, *part))
+                    .count() as u8,
+                implemented: implemented.contains(&day),
+            })
+            .collect();
+
+        // The second star of the last day is given for all the others
+        if let Some((last, others)) = days.split_last_mut() {
+            if last.stars == 1 && others.iter().all(|day| day.stars == 2) {
+                last.stars = 2;
+            }
+        }
+
+        Self { year, days }
+    }
+
+    /// Returns the stars earned in the year
+    pub fn stars(&self) -> u32 {
+        self.days.iter().map(|day| day.stars as u32).sum()
+    }
+
+    /// Returns the stars the year has to offer
+    pub fn max_stars(&self) -> u32 {
+        self.days.len() as u32 * 2
+    }
+}
+
+/// Renders the calendar of every year for the terminal: gold `**` for two
+/// stars, silver `* ` for one, and `. ` for the days with a solver but no
+/// confirmed answer yet
+pub fn render(years: &[YearProgress], with_color: bool) -> String {
+    let last_day = years
+        .iter()
+        .map(|year| year.days.len())
+        .max()
+        .unwrap_or_default();
+    let header: Vec<String> =
+        (1..=last_day).map(|day| format!("{:>2}", day)).collect();
+    let mut lines = vec![format!("     {}", header.join(" "))];
+
+    for year in years {
+        let cells: Vec<String> = year
+            .days
+            .iter()
+            .map(|day| match (day.stars, day.implemented, with_color) {
+                (2, _, true) => "**".yellow().bold().to_string(),
+                (2, _, false) => "**".to_string(),
+                (1, _, true) => format!("{} ", "*".bright_black()),
+                (1, _, false) => "* ".to_string(),
+                (_, true, true) => format!("{} ", ".".dimmed()),
+                (_, true, false) => ". ".to_string(),
+                (_, false, _) => "  ".to_string(),
+            })
+            .collect();
+        let padding = "   ".repeat(last_day - year.days.len());
+        let total = format!("{}/{}", year.stars(), year.max_stars());
+        let total = match with_color {
+            true => total.yellow().to_string(),
+            false => total,
+        };
+
+        lines.push(format!(
+            "{} {}{} {}",
+            year.year,
+            cells.join(" "),
+            padding,
+            total
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Renders the calendar of every year as a Markdown table, with the stars
+/// of each year and the overall total. Like in the terminal, the days with
+/// a solver but no confirmed answer yet are marked with `.`
+pub fn render_markdown(years: &[YearProgress]) -> String {
+    let last_day = years
+        .iter()
+        .map(|year| year.days.len())
+        .max()
+        .unwrap_or_default();
+    let days: Vec<String> = (1..=last_day).map(|day| day.to_string()).collect();
+    let mut lines = vec![
+        format!("| Year | {} | Stars |", days.join(" | ")),
+        format!("| --- |{} ---: |", " :-: |".repeat(last_day)),
+    ];
+
+    for year in years {
+        let mut cells: Vec<&str> = year
+            .days
+            .iter()
+            .map(|day| match (day.stars, day.implemented) {
+                (2, _) => "⭐⭐",
+                (1, _) => "⭐",
+                (_, true) => ".",
+                (_, false) => "",
+            })
+            .collect();
+        cells.resize(last_day, "");
+
+        lines.push(format!(
+            "| {} | {} | {}/{} |",
+            year.year,
+            cells.join(" | "),
+            year.stars(),
+            year.max_stars()
+        ));
+    }
+
+    let stars: u32 = years.iter().map(YearProgress::stars).sum();
+    let max_stars: u32 = years.iter().map(YearProgress::max_stars).sum();
+    lines.push(String::new());
+    lines.push(format!("**Total: {}/{} stars**", stars, max_stars));
+    lines.push(String::new());
+    lines.push("`.` Implemented, without a confirmed answer yet".to_string());
+
+    lines.join("\n")
+}
+
+/// Replaces the lines between [`SECTION_START`] and [`SECTION_END`] with
+/// `content`. Returns `None` when the text has no such section.
+pub fn replace_section(text: &str, content: &str) -> Option<String> {
+    let start = text.find(SECTION_START)? + SECTION_START.len();
+    let end = start + text[start..].find(SECTION_END)?;
+
+    Some(format!(
+        "{}\n{}\n{}",
+        &text[..start],
+        content.trim_end(),
+        &text[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::http::SubmitOutcome;
+
+    fn ledger(answers: &[(u32, Part)]) -> Ledger {
+        let mut ledger = Ledger::default();
+        for (day, part) in answers {
+            ledger.record(*day, *part, "42", SubmitOutcome::Right);
+        }
+
+        ledger
+    }
+
+    fn year_2025(stars: &[u8]) -> YearProgress {
+        YearProgress {
+            year: 2025,
+            days: (1..=12)
+                .map(|day| DayProgress {
+                    day,
+                    stars: stars.get(day as usize - 1).copied().unwrap_or(0),
+                    implemented: day <= 3,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_stars_from_ledger() {
+        let ledger = ledger(&[(1, Part::P1), (1, Part::P2), (2, Part::P1)]);
+        let progress = YearProgress::new(2024, &[1, 2, 3], &ledger);
+
+        assert_eq!(progress.days.len(), 25);
+        assert_eq!(
+            progress.days[..4],
+            [
+                DayProgress {
+                    day: 1,
+                    stars: 2,
+                    implemented: true
+                },
+                DayProgress {
+                    day: 2,
+                    stars: 1,
+                    implemented: true
+                },
+                DayProgress {
+                    day: 3,
+                    stars: 0,
+                    implemented: true
+                },
+                DayProgress {
+                    day: 4,
+                    stars: 0,
+                    implemented: false
+                },
+            ]
+        );
+        assert_eq!(progress.stars(), 3);
+        assert_eq!(progress.max_stars(), 50);
+    }
+
+    #[test]
+    fn test_last_day_second_star() {
+        let mut answers: Vec<(u32, Part)> = (1..12)
+            .flat_map(|day| [(day, Part::P1), (day, Part::P2)])
+            .collect();
+        answers.push((12, Part::P1));
+
+        let complete = YearProgress::new(2025, &[], &ledger(&answers));
+        assert_eq!(complete.stars(), 24);
+
+        answers.remove(0);
+        let incomplete = YearProgress::new(2025, &[], &ledger(&answers));
+        assert_eq!(incomplete.days[11].stars, 1);
+    }
+
+    #[test]
+    fn test_render() {
+        let expected = [
+            "      1  2  3  4  5  6  7  8  9 10 11 12",
+            "2025 ** *  .                             3/24",
+        ]
+        .join("\n");
+
+        assert_eq!(render(&[year_2025(&[2, 1])], false), expected);
+    }
+
+    #[test]
+    fn test_render_pads_shorter_years() {
+        let year_2024 = YearProgress::new(2024, &[], &Ledger::default());
+        let rendered = render(&[year_2024, year_2025(&[])], false);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert!(lines[0].ends_with("24 25"));
+        assert_eq!(lines[1].len(), lines[2].len());
+        assert!(lines[2].ends_with(" 0/24"));
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let expected = [
+            "| Year | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | Stars |",
+            "| --- | :-: | :-: | :-: | :-: | :-: | :-: | :-: | :-: | :-: | :-: | :-: | :-: | ---: |",
+            "| 2025 | ⭐⭐ | ⭐ | . |  |  |  |  |  |  |  |  |  | 3/24 |",
+            "",
+            "**Total: 3/24 stars**",
+            "",
+            "`.` Implemented, without a confirmed answer yet",
+        ]
+        .join("\n");
+
+        assert_eq!(render_markdown(&[year_2025(&[2, 1])]), expected);
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!(
+            "# Title\n\n{}\nold\ncontent\n{}\n\nMore text\n",
+            SECTION_START, SECTION_END
+        );
+
+        assert_eq!(
+            replace_section(&readme, "new\n").unwrap(),
+            format!(
+                "# Title\n\n{}\nnew\n{}\n\nMore text\n",
+                SECTION_START, SECTION_END
+            )
+        );
+    }
+
+    #[test]
+    fn test_replace_missing_section() {
+        assert_eq!(replace_section("# Title\n", "new"), None);
+        assert_eq!(replace_section(SECTION_START, "new"), None);
+    }
+}
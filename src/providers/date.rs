@@ -1,4 +1,5 @@
-use chrono::Datelike;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use chrono_tz::{America::New_York, Tz};
 
 /// The months of the year, each one is one variant.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -17,27 +18,10 @@ pub enum Month {
     December,
 }
 
-/// A trait for providing the current date components such as year and month.
-pub trait CurrentDateProvider {
-    /// Returns the current year.
-    fn current_year(&self) -> u32;
-    /// Returns the current month.
-    fn current_month(&self) -> Month;
-    /// Returns the current day
-    fn current_day(&self) -> u32;
-}
-
-/// Implementation of the `DateInfoProvider` trait
-#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
-pub struct DateAdapter {}
-
-impl CurrentDateProvider for DateAdapter {
-    fn current_year(&self) -> u32 {
-        chrono::Local::now().year() as u32
-    }
-
-    fn current_month(&self) -> Month {
-        match chrono::Local::now().month() {
+impl Month {
+    /// Returns the month of the given date
+    pub fn of(date: &impl Datelike) -> Self {
+        match date.month() {
             1 => Month::January,
             2 => Month::February,
             3 => Month::March,
@@ -54,8 +38,49 @@ impl CurrentDateProvider for DateAdapter {
         }
     }
 
-    fn current_day(&self) -> u32 {
-        chrono::Local::now().day()
+    /// Returns the number of the month, starting at 1 for January
+    pub fn number(self) -> u32 {
+        self as u32 + 1
+    }
+}
+
+/// A trait for providing the current date, in the timezone of the provider.
+pub trait CurrentDateProvider {
+    /// Returns the current date. Its year, month and day all come from the
+    /// same instant, so they are consistent even around midnight.
+    fn today(&self) -> NaiveDate;
+    /// Returns the current instant
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Implementation of the `DateInfoProvider` trait, giving the date in the
+/// given timezone. Defaults to AoC's timezone, where the puzzles unlock at
+/// midnight.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DateAdapter {
+    timezone: Tz,
+}
+
+impl DateAdapter {
+    /// Creates an adapter giving the date in the given timezone
+    pub fn new(timezone: Tz) -> Self {
+        Self { timezone }
+    }
+}
+
+impl Default for DateAdapter {
+    fn default() -> Self {
+        Self::new(New_York)
+    }
+}
+
+impl CurrentDateProvider for DateAdapter {
+    fn today(&self) -> NaiveDate {
+        Utc::now().with_timezone(&self.timezone).date_naive()
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
     }
 }
 
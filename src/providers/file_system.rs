@@ -1,7 +1,8 @@
 use log::{debug, trace};
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{BufWriter, Result as IOResult, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 /// This trait defines files that can be opened/created in write mode
 pub trait FSWrite {
@@ -15,14 +16,75 @@ pub trait FSWrite {
     ///
     /// # Arguments
     ///
-    /// * `path` - A `PathBuf` indicating the path of the file to open.
+    /// * `path` - A `Path` indicating the path of the file to open.
     ///
     /// # Returns
     ///
     /// * An [`IOResult`] which is either:
     ///   - `Ok(Self::Writer)`: A writer for writing to the file.
     ///   - `Err(e)`: An I/O error if the file cannot be opened.
-    fn open(&self, path: &PathBuf) -> IOResult<Self::Writer>;
+    fn open(&self, path: &Path) -> IOResult<Self::Writer>;
+}
+
+/// This trait defines files that only their owner can read and write
+pub trait FSWritePrivate: FSWrite {
+    /// Opens the file at the given `path` for writing, restricting its
+    /// permissions to its owner (`0600`) before anything is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `Path` indicating the path of the file to open.
+    ///
+    /// # Returns
+    ///
+    /// * An [`IOResult`] with a writer for the file, or an I/O error if it
+    ///   cannot be opened or restricted.
+    fn open_private(&self, path: &Path) -> IOResult<Self::Writer>;
+}
+
+/// This trait defines files whose contents can be read at once
+pub trait FSRead {
+    /// Reads the whole file at the given `path` into a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `Path` indicating the path of the file to read.
+    ///
+    /// # Returns
+    ///
+    /// * An [`IOResult`] with the contents of the file, or an I/O error if
+    ///   it cannot be read.
+    fn read_to_string(&self, path: &Path) -> IOResult<String>;
+}
+
+/// This trait defines an operation to list the entries of a directory
+pub trait FSReadDir {
+    /// Lists the entries of the directory at the given `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `Path` indicating the directory to list.
+    ///
+    /// # Returns
+    ///
+    /// * An [`IOResult`] with the path of every entry, sorted, or an I/O error
+    ///   if the directory cannot be read.
+    fn read_dir(&self, path: &Path) -> IOResult<Vec<PathBuf>>;
+}
+
+/// This trait defines an operation to move a file to a new path
+pub trait FSRename {
+    /// Renames the file at `from` to `to`, replacing `to` if it exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - A `Path` indicating the file to rename.
+    /// * `to` - A `Path` indicating the new name of the file.
+    ///
+    /// # Returns
+    ///
+    /// * An [`IOResult`] indicating success or failure of the operation.
+    fn rename(&self, from: &Path, to: &Path) -> IOResult<()>;
 }
 
 /// This trait defines an operation to check if a file indeed exists in the filesystem
@@ -31,12 +93,27 @@ pub trait FSExists {
     ///
     /// # Arguments
     ///
-    /// * `path` - A `PathBuf` indicating the path of the file to check.
+    /// * `path` - A `Path` indicating the path of the file to check.
     ///
     /// # Returns
     ///
     /// * `true` if the file exists, `false` otherwise.
-    fn exists(&self, path: &PathBuf) -> bool;
+    fn exists(&self, path: &Path) -> bool;
+}
+
+/// This trait defines an operation to know when a file was last written
+pub trait FSModified {
+    /// Returns the last modification time of the file at the given `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `Path` indicating the path of the file to check.
+    ///
+    /// # Returns
+    ///
+    /// * An [`IOResult`] with the modification time, or an I/O error if
+    ///   the file cannot be inspected.
+    fn modified(&self, path: &Path) -> IOResult<SystemTime>;
 }
 
 /// A local file system implementation of the `FileSystem` trait.
@@ -49,12 +126,17 @@ pub struct LocalFSAdapter;
 impl FSWrite for LocalFSAdapter {
     type Writer = BufWriter<File>;
 
-    fn open(&self, path: &PathBuf) -> IOResult<Self::Writer> {
+    fn open(&self, path: &Path) -> IOResult<Self::Writer> {
         trace!("open {}", path.display());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
         let file = OpenOptions::new()
             .read(true)
             .write(true)
             .create(true)
+            .truncate(true)
             .open(path)?;
 
         debug!("File opened...");
@@ -63,8 +145,191 @@ impl FSWrite for LocalFSAdapter {
     }
 }
 
+impl FSWritePrivate for LocalFSAdapter {
+    fn open_private(&self, path: &Path) -> IOResult<Self::Writer> {
+        trace!("open_private {}", path.display());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+            options.mode(0o600);
+            // The mode only applies to new files
+            if path.exists() {
+                fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+            }
+        }
+
+        Ok(BufWriter::new(options.open(path)?))
+    }
+}
+
+impl FSRead for LocalFSAdapter {
+    fn read_to_string(&self, path: &Path) -> IOResult<String> {
+        trace!("read {}", path.display());
+        fs::read_to_string(path)
+    }
+}
+
+impl FSReadDir for LocalFSAdapter {
+    fn read_dir(&self, path: &Path) -> IOResult<Vec<PathBuf>> {
+        trace!("read_dir {}", path.display());
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<IOResult<Vec<PathBuf>>>()?;
+        entries.sort();
+
+        Ok(entries)
+    }
+}
+
+impl FSRename for LocalFSAdapter {
+    fn rename(&self, from: &Path, to: &Path) -> IOResult<()> {
+        trace!("rename {} to {}", from.display(), to.display());
+        fs::rename(from, to)
+    }
+}
+
 impl FSExists for LocalFSAdapter {
-    fn exists(&self, path: &PathBuf) -> bool {
+    fn exists(&self, path: &Path) -> bool {
         path.exists()
     }
 }
+
+impl FSModified for LocalFSAdapter {
+    fn modified(&self, path: &Path) -> IOResult<SystemTime> {
+        trace!("modified {}", path.display());
+        fs::metadata(path)?.modified()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::io::{Error, ErrorKind};
+    use std::rc::Rc;
+
+    type Files = Rc<RefCell<HashMap<PathBuf, Vec<u8>>>>;
+
+    /// In-memory file system, shared by the mock and the files it opens
+    #[derive(Default)]
+    pub struct FSMock {
+        files: Files,
+        modified: RefCell<HashMap<PathBuf, SystemTime>>,
+        private: RefCell<Vec<PathBuf>>,
+    }
+
+    /// A file of the [`FSMock`] opened for writing
+    pub struct FileMock {
+        path: PathBuf,
+        files: Files,
+    }
+
+    impl Write for FileMock {
+        fn write(&mut self, buf: &[u8]) -> IOResult<usize> {
+            self.files
+                .borrow_mut()
+                .entry(self.path.clone())
+                .or_default()
+                .extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> IOResult<()> {
+            Ok(())
+        }
+    }
+
+    impl FSMock {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn insert_file(&mut self, path: &Path, content: &str) {
+            self.files
+                .borrow_mut()
+                .insert(path.to_path_buf(), content.as_bytes().to_vec());
+            self.set_modified(path, SystemTime::now());
+        }
+
+        /// Overrides the modification time of the file
+        pub fn set_modified(&mut self, path: &Path, time: SystemTime) {
+            self.modified.borrow_mut().insert(path.to_path_buf(), time);
+        }
+
+        /// Returns whether the file was opened with
+        /// [`FSWritePrivate::open_private`]
+        pub fn is_private(&self, path: &Path) -> bool {
+            self.private.borrow().iter().any(|private| private == path)
+        }
+
+        /// Returns the content of the file, if it was written
+        pub fn file(&self, path: &Path) -> Option<String> {
+            self.files
+                .borrow()
+                .get(path)
+                .map(|content| String::from_utf8_lossy(content).to_string())
+        }
+    }
+
+    impl FSWrite for FSMock {
+        type Writer = FileMock;
+
+        fn open(&self, path: &Path) -> IOResult<Self::Writer> {
+            self.files
+                .borrow_mut()
+                .insert(path.to_path_buf(), Vec::new());
+            self.modified
+                .borrow_mut()
+                .insert(path.to_path_buf(), SystemTime::now());
+
+            Ok(FileMock {
+                path: path.to_path_buf(),
+                files: Rc::clone(&self.files),
+            })
+        }
+    }
+
+    impl FSWritePrivate for FSMock {
+        fn open_private(&self, path: &Path) -> IOResult<Self::Writer> {
+            self.private.borrow_mut().push(path.to_path_buf());
+
+            self.open(path)
+        }
+    }
+
+    impl FSRead for FSMock {
+        fn read_to_string(&self, path: &Path) -> IOResult<String> {
+            let content = self
+                .file(path)
+                .ok_or_else(|| Error::from(ErrorKind::NotFound))?;
+
+            Ok(content)
+        }
+    }
+
+    impl FSExists for FSMock {
+        fn exists(&self, path: &Path) -> bool {
+            self.files.borrow().contains_key(path)
+        }
+    }
+
+    impl FSModified for FSMock {
+        fn modified(&self, path: &Path) -> IOResult<SystemTime> {
+            self.modified
+                .borrow()
+                .get(path)
+                .copied()
+                .ok_or_else(|| Error::from(ErrorKind::NotFound))
+        }
+    }
+}
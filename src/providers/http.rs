@@ -1,7 +1,13 @@
 //! Dependencies to make HTTP requests
+use crate::constants::DEFAULT_USER_AGENT;
 use crate::Puzzle;
-use log::trace;
-use reqwest::blocking::Client;
+use log::{debug, trace, warn};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Errors from the HTTP Provider
@@ -15,6 +21,15 @@ pub enum HTTPError {
 
     #[error("Failed to parse JSON: {0}")]
     ParseError(String),
+
+    #[error("Unable to use the cached copy: {0}")]
+    CacheError(String),
+
+    #[error("{0} is not unlocked yet")]
+    NotUnlocked(String),
+
+    #[error("The AoC session cookie is invalid or expired, update it with `aoc auth set`")]
+    ExpiredCookie,
 }
 
 /// Trait to build and send HTTP Requests (not async)
@@ -22,28 +37,74 @@ pub trait HTTPProvider {
     /// Prepares a GET request to the specified endpoint
     fn get(&self, endpoint: &impl AOCUrl) -> Result<String, HTTPError>;
 
+    /// Sends a POST request with the given form fields to the specified
+    /// endpoint
+    fn post(
+        &self,
+        endpoint: &impl AOCUrl,
+        form: &[(&str, &str)],
+    ) -> Result<String, HTTPError>;
+
     /// Adds a cookie that later will be used to fetch the data
     fn set_cookie(&mut self, cookie: String);
 
     /// Retrieves the stored cookie
     fn get_cookie(&self) -> Option<String>;
+
+    /// Sets the User-Agent identifying the requests
+    fn set_user_agent(&mut self, user_agent: String);
 }
 
-#[derive(Default)]
+/// [`HTTPProvider`] sending the requests to AoC.
+///
+/// Every request goes through the same client and identifies itself with
+/// the User-Agent. Consecutive requests to a host are spaced by the
+/// throttle, and the ones failing with a connection error are retried,
+/// waiting twice as long before each new attempt. GET requests are also
+/// retried after a timeout or a 5xx status.
 pub struct HTTPAdapter {
     aoc_cookie: Option<String>,
+    client: Client,
+    user_agent: String,
+    throttle: Duration,
+    retries: u32,
+    backoff: Duration,
+    last_requests: Mutex<HashMap<String, Instant>>,
+}
+
+impl Default for HTTPAdapter {
+    /// Identifies as [`DEFAULT_USER_AGENT`], waits a second between
+    /// requests and retries 3 times, starting with a 500ms wait
+    fn default() -> Self {
+        Self {
+            aoc_cookie: None,
+            client: Client::default(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            throttle: Duration::from_secs(1),
+            retries: 3,
+            backoff: Duration::from_millis(500),
+            last_requests: Mutex::new(HashMap::new()),
+        }
+    }
 }
 
 /// This encapsulates endpoints to AOC website
 pub trait AOCUrl {
     /// Fetch the required URL
     fn url(&self) -> String;
+
+    /// Whether the URL is the page or the input of a puzzle, which AoC
+    /// answers with a 404 until the puzzle is unlocked
+    fn is_puzzle(&self) -> bool {
+        false
+    }
 }
 
 /// AOCUrl implementation
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct URL {
     url: String,
+    puzzle: bool,
 }
 
 impl URL {
@@ -54,7 +115,57 @@ impl URL {
             puzzle.year(),
             puzzle.day()
         );
-        Self { url: endpoint }
+        Self {
+            url: endpoint,
+            puzzle: true,
+        }
+    }
+
+    /// Creates the AOC Url of the page describing the puzzle
+    pub fn puzzle(puzzle: &Puzzle) -> Self {
+        let endpoint = format!(
+            "https://adventofcode.com/{}/day/{}",
+            puzzle.year(),
+            puzzle.day()
+        );
+        Self {
+            url: endpoint,
+            puzzle: true,
+        }
+    }
+
+    /// Creates the AOC Url of the JSON of a private leaderboard
+    pub fn leaderboard(year: u32, id: u64) -> Self {
+        let endpoint = format!(
+            "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+            year, id
+        );
+        Self {
+            url: endpoint,
+            puzzle: false,
+        }
+    }
+
+    /// Creates the AOC Url of the settings page, which is only available to
+    /// logged in users
+    pub fn settings() -> Self {
+        Self {
+            url: "https://adventofcode.com/settings".to_string(),
+            puzzle: false,
+        }
+    }
+
+    /// Creates the AOC Url where the answers of the puzzle are submitted
+    pub fn answer(puzzle: &Puzzle) -> Self {
+        let endpoint = format!(
+            "https://adventofcode.com/{}/day/{}/answer",
+            puzzle.year(),
+            puzzle.day()
+        );
+        Self {
+            url: endpoint,
+            puzzle: false,
+        }
     }
 }
 
@@ -62,33 +173,118 @@ impl AOCUrl for URL {
     fn url(&self) -> String {
         self.url.clone()
     }
+
+    fn is_puzzle(&self) -> bool {
+        self.puzzle
+    }
 }
 
-impl HTTPProvider for HTTPAdapter {
-    fn get(&self, endpoint: &impl AOCUrl) -> Result<String, HTTPError> {
-        trace!("GET {:?}...", endpoint.url());
-        let client = Client::default();
-        let endpoint = endpoint.url();
+impl HTTPAdapter {
+    /// Sets the minimum time between two requests to the same host
+    pub fn set_throttle(&mut self, throttle: Duration) -> &mut Self {
+        self.throttle = throttle;
+
+        self
+    }
+
+    /// Sets how many times a request is retried after a transient error,
+    /// and how long to wait before the first retry
+    pub fn set_retries(
+        &mut self,
+        retries: u32,
+        backoff: Duration,
+    ) -> &mut Self {
+        self.retries = retries;
+        self.backoff = backoff;
+
+        self
+    }
+
+    /// Waits until the throttle allows a new request to the host of the
+    /// endpoint
+    fn throttle(&self, endpoint: &str) {
+        let host = reqwest::Url::parse(endpoint)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_default();
+        let mut last_requests = self
+            .last_requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(last) = last_requests.get(&host) {
+            let wait = self.throttle.saturating_sub(last.elapsed());
+            if !wait.is_zero() {
+                debug!("Throttling the request to {} for {:?}", host, wait);
+                thread::sleep(wait);
+            }
+        }
+
+        last_requests.insert(host, Instant::now());
+    }
+
+    /// Adds the session cookie and the User-Agent to the request, sends it
+    /// and returns the body of the response. `description` identifies the
+    /// request in the error messages.
+    ///
+    /// Only `idempotent` requests are retried after a timeout or a 5xx
+    /// status, as the server may have processed the others.
+    fn send(
+        &self,
+        request: RequestBuilder,
+        endpoint: &impl AOCUrl,
+        description: &str,
+        idempotent: bool,
+    ) -> Result<String, HTTPError> {
         let aoc_cookie = self.get_cookie().ok_or(
             HTTPError::MissingEnvVarError("AOC_COOKIE not set".into()),
         )?;
         let cookie_value = format!("{}={}", "session", aoc_cookie);
-
-        let response = client
-            .get(endpoint.clone())
+        let request = request
             .header(reqwest::header::COOKIE, cookie_value)
-            .send()
-            .map_err(|e| {
-                HTTPError::FetchError(format!(
-                    "GET {} failed: {}",
-                    endpoint,
-                    e.to_string()
-                ))
-            })?;
-
-        let result = response
-            .error_for_status()
-            .map_err(|e| HTTPError::FetchError(format!("{}", e)))?
+            .header(reqwest::header::USER_AGENT, &self.user_agent);
+
+        let mut backoff = self.backoff;
+        let mut attempt = 0;
+        let response = loop {
+            self.throttle(&endpoint.url());
+
+            let error = match request
+                .try_clone()
+                .expect("requests with a form body can be cloned")
+                .send()
+            {
+                Ok(response)
+                    if idempotent && response.status().is_server_error() =>
+                {
+                    check_status(response, endpoint).unwrap_err()
+                }
+                Ok(response) => break response,
+                Err(e) => {
+                    let error = HTTPError::FetchError(format!(
+                        "{} failed: {}",
+                        description, e
+                    ));
+                    let transient =
+                        e.is_connect() || (idempotent && e.is_timeout());
+                    if !transient {
+                        return Err(error);
+                    }
+                    error
+                }
+            };
+
+            if attempt == self.retries {
+                return Err(error);
+            }
+
+            warn!("{}, retrying in {:?}", error, backoff);
+            thread::sleep(backoff);
+            backoff *= 2;
+            attempt += 1;
+        };
+
+        let result = check_status(response, endpoint)?
             .text()
             .map_err(|e| HTTPError::ParseError(e.to_string()))?;
 
@@ -96,6 +292,56 @@ impl HTTPProvider for HTTPAdapter {
 
         Ok(result)
     }
+}
+
+/// Maps the error statuses to HTTPErrors. For the puzzle endpoints, AoC
+/// answers 404 when the puzzle is not unlocked yet, and 400 when the session
+/// cookie is not valid. Other endpoints get the status as is.
+fn check_status(
+    response: Response,
+    endpoint: &impl AOCUrl,
+) -> Result<Response, HTTPError> {
+    match response.status() {
+        StatusCode::NOT_FOUND if endpoint.is_puzzle() => {
+            Err(HTTPError::NotUnlocked(endpoint.url()))
+        }
+        StatusCode::BAD_REQUEST if endpoint.is_puzzle() => {
+            Err(HTTPError::ExpiredCookie)
+        }
+        _ => response
+            .error_for_status()
+            .map_err(|e| HTTPError::FetchError(format!("{}", e))),
+    }
+}
+
+impl HTTPProvider for HTTPAdapter {
+    fn get(&self, endpoint: &impl AOCUrl) -> Result<String, HTTPError> {
+        trace!("GET {:?}...", endpoint.url());
+        let url = endpoint.url();
+
+        self.send(
+            self.client.get(&url),
+            endpoint,
+            &format!("GET {}", url),
+            true,
+        )
+    }
+
+    fn post(
+        &self,
+        endpoint: &impl AOCUrl,
+        form: &[(&str, &str)],
+    ) -> Result<String, HTTPError> {
+        trace!("POST {:?}...", endpoint.url());
+        let url = endpoint.url();
+
+        self.send(
+            self.client.post(&url).form(form),
+            endpoint,
+            &format!("POST {}", url),
+            false,
+        )
+    }
 
     fn set_cookie(&mut self, cookie: String) {
         self.aoc_cookie = Some(cookie);
@@ -104,6 +350,10 @@ impl HTTPProvider for HTTPAdapter {
     fn get_cookie(&self) -> Option<String> {
         self.aoc_cookie.clone()
     }
+
+    fn set_user_agent(&mut self, user_agent: String) {
+        self.user_agent = user_agent;
+    }
 }
 
 #[cfg(test)]
@@ -115,7 +365,9 @@ pub mod tests {
     pub struct HttpProviderMock {
         responses: HashMap<String, Result<String, HTTPError>>,
         cookie: Option<String>,
+        user_agent: Option<String>,
         calls: RefCell<Vec<String>>,
+        forms: RefCell<Vec<Vec<(String, String)>>>,
         assert_called_with: Option<String>,
         assert_cookie_value: Option<String>,
     }
@@ -126,6 +378,20 @@ pub mod tests {
             self.responses.get(&endpoint.url()).unwrap().clone()
         }
 
+        fn post(
+            &self,
+            endpoint: &impl AOCUrl,
+            form: &[(&str, &str)],
+        ) -> Result<String, HTTPError> {
+            self.calls.borrow_mut().push(endpoint.url());
+            self.forms.borrow_mut().push(
+                form.iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            );
+            self.responses.get(&endpoint.url()).unwrap().clone()
+        }
+
         fn set_cookie(&mut self, cookie: String) {
             self.cookie = Some(cookie);
         }
@@ -133,6 +399,16 @@ pub mod tests {
         fn get_cookie(&self) -> Option<String> {
             unimplemented!("`get_cookie` is not needed in the mocks`")
         }
+
+        fn set_user_agent(&mut self, user_agent: String) {
+            self.user_agent = Some(user_agent);
+        }
+    }
+
+    impl Default for HttpProviderMock {
+        fn default() -> Self {
+            Self::new()
+        }
     }
 
     impl HttpProviderMock {
@@ -140,7 +416,9 @@ pub mod tests {
             Self {
                 responses: HashMap::new(),
                 cookie: None,
+                user_agent: None,
                 calls: RefCell::new(Vec::new()),
+                forms: RefCell::new(Vec::new()),
                 assert_called_with: None,
                 assert_cookie_value: None,
             }
@@ -153,6 +431,16 @@ pub mod tests {
             self.responses.insert(endpoint, Err(error));
         }
 
+        /// Returns the User-Agent that was set, if any
+        pub fn user_agent(&self) -> Option<String> {
+            self.user_agent.clone()
+        }
+
+        /// Returns the form fields of every POST request received
+        pub fn posted_forms(&self) -> Vec<Vec<(String, String)>> {
+            self.forms.borrow().clone()
+        }
+
         pub fn assert_called_with(&mut self, endpoint: String) {
             self.assert_called_with = Some(endpoint);
         }
@@ -166,7 +454,7 @@ pub mod tests {
         fn drop(&mut self) {
             if let Some(expected) = &self.assert_called_with {
                 assert!(
-                    self.calls.borrow().contains(&expected),
+                    self.calls.borrow().contains(expected),
                     "Expected call to {} not found. Calls: {:?}",
                     expected,
                     self.calls.borrow()
@@ -7,7 +7,7 @@
 //!
 //! This module encapsulates two core services:
 //! * FSService: Manages file system operations, such as extracting and writing puzzle
-//! templates.
+//!   templates.
 //! * HTTPService: Manages HTTP operations, such as downloading puzzle inputs from the
 //!   Advent of Code website.
 
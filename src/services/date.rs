@@ -1,11 +1,17 @@
 //! This module provides the `DateService`, which offers utilities for calculating
-//! default dates
+//! default dates, and when the puzzles unlock
 
+use crate::calendar::last_day;
+use crate::constants::UNLOCK_UTC_OFFSET_HOURS;
 use crate::providers::date::{CurrentDateProvider, DateAdapter, Month};
+use crate::Puzzle;
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 use log::trace;
+use std::time::Duration;
 
 /// `DateService` is a struct that provides functionalities to calculate default dates
-/// such as default year and default day based on the current date.
+/// such as default year and default day based on the current date, in the
+/// timezone of its provider.
 ///
 /// The `DateService` is generic over a type `D` which must implement the
 /// `CurrentDateProvider` trait.
@@ -31,33 +37,112 @@ where
     pub fn default_year(&self) -> u32 {
         trace!("Calculating default year...");
 
-        let current_month = self.provider.current_month();
-        let current_year = self.provider.current_year();
+        let today = self.provider.today();
+        let current_year = today.year() as u32;
 
-        match current_month {
+        match Month::of(&today) {
             Month::December => current_year,
             _ => current_year - 1,
         }
     }
 
+    /// Returns the year of the latest event: the current one, even before
+    /// December, as its puzzles can already be waited for.
+    pub fn latest_year(&self) -> u32 {
+        self.provider.today().year() as u32
+    }
+
     /// It calculates the default day.
     /// It should be the current day if:
     /// - We are on December AND
-    /// - We are between 1 and 25
+    /// - We are between 1 and the last day of the event: 25, or 12 from
+    ///   2025 on
     ///
     /// Otherwise, it will be 1
     pub fn default_day(&self) -> u32 {
         trace!("Calculating default day...");
 
-        let current_month = self.provider.current_month();
-        let current_day = self.provider.current_day();
+        let today = self.provider.today();
+        let current_day = today.day();
+        let last_day = last_day(today.year() as u32);
 
-        if current_month == Month::December && (1..=25).contains(&current_day) {
+        if Month::of(&today) == Month::December
+            && (1..=last_day).contains(&current_day)
+        {
             current_day
         } else {
             1
         }
     }
+
+    /// Returns the instant the puzzle unlocks: midnight of its day in
+    /// UTC-5, where AoC publishes the puzzles.
+    pub fn unlock_time(&self, puzzle: &Puzzle) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(UNLOCK_UTC_OFFSET_HOURS * 3600)
+            .expect("The unlock offset is a valid UTC offset");
+
+        offset
+            .with_ymd_and_hms(puzzle.year() as i32, 12, puzzle.day(), 0, 0, 0)
+            .single()
+            .expect("Every puzzle is on a valid December day")
+    }
+
+    /// Returns how long until the puzzle unlocks, or `None` when it is
+    /// already unlocked.
+    pub fn time_until_unlock(&self, puzzle: &Puzzle) -> Option<Duration> {
+        trace!("Calculating the time until the puzzle unlocks...");
+
+        let unlock = self.unlock_time(puzzle).with_timezone(&Utc);
+
+        (unlock - self.provider.now())
+            .to_std()
+            .ok()
+            .filter(|d| !d.is_zero())
+    }
+
+    /// Waits until the puzzle unlocks. `wait` is called with the time left
+    /// and how long to sleep: until the next whole second of the countdown,
+    /// so the caller can show it.
+    pub fn wait_for_unlock(
+        &self,
+        puzzle: &Puzzle,
+        mut wait: impl FnMut(Duration, Duration),
+    ) {
+        while let Some(remaining) = self.time_until_unlock(puzzle) {
+            wait(remaining, next_tick(remaining));
+        }
+    }
+}
+
+/// Returns how long to sleep until the next whole second of the countdown,
+/// or until the unlock when it is closer
+fn next_tick(remaining: Duration) -> Duration {
+    match remaining.subsec_nanos() {
+        0 => Duration::from_secs(1).min(remaining),
+        nanos => Duration::from_nanos(nanos as u64),
+    }
+}
+
+/// Formats the time left until an unlock as `[Nd ]HH:MM:SS`, rounding the
+/// seconds up so the countdown never shows zero before the unlock
+pub fn format_countdown(remaining: Duration) -> String {
+    let mut seconds = remaining.as_secs();
+    if remaining.subsec_nanos() > 0 {
+        seconds += 1;
+    }
+
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+
+    match days {
+        0 => time,
+        _ => format!("{}d {}", days, time),
+    }
 }
 
 impl Default for DateService<DateAdapter> {
@@ -69,31 +154,44 @@ impl Default for DateService<DateAdapter> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::NaiveDate;
     use rstest::rstest;
+    use std::cell::Cell;
 
     struct DateInfoProviderMock {
-        month: Month,
-        year: u32,
-        day: u32,
+        today: NaiveDate,
+        now: Cell<DateTime<Utc>>,
     }
 
     impl DateInfoProviderMock {
         fn new(year: u32, month: Month, day: u32) -> Self {
-            Self { year, month, day }
+            Self {
+                today: NaiveDate::from_ymd_opt(
+                    year as i32,
+                    month.number(),
+                    day,
+                )
+                .unwrap(),
+                now: Cell::new(Utc::now()),
+            }
         }
-    }
 
-    impl CurrentDateProvider for DateInfoProviderMock {
-        fn current_year(&self) -> u32 {
-            self.year
+        /// Returns a mock of the given instant, in UTC
+        fn at(now: &str) -> Self {
+            Self {
+                now: Cell::new(now.parse().unwrap()),
+                ..Self::new(2024, Month::December, 1)
+            }
         }
+    }
 
-        fn current_month(&self) -> Month {
-            self.month
+    impl CurrentDateProvider for DateInfoProviderMock {
+        fn today(&self) -> NaiveDate {
+            self.today
         }
 
-        fn current_day(&self) -> u32 {
-            self.day
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
         }
     }
 
@@ -197,4 +295,125 @@ mod tests {
 
         assert_eq!(result, 1u32);
     }
+
+    #[rstest]
+    #[case(1)]
+    #[case(12)]
+    fn default_day_on_december_since_2025(#[case] day: u32) {
+        let provider_mock =
+            DateInfoProviderMock::new(2025, Month::December, day);
+        let service = DateService::new(provider_mock);
+
+        assert_eq!(service.default_day(), day);
+    }
+
+    #[rstest]
+    #[case(13)]
+    #[case(25)]
+    fn default_day_after_the_last_day_since_2025(#[case] day: u32) {
+        let provider_mock =
+            DateInfoProviderMock::new(2025, Month::December, day);
+        let service = DateService::new(provider_mock);
+
+        assert_eq!(service.default_day(), 1);
+    }
+
+    #[test]
+    fn unlock_time_is_midnight_in_utc_minus_5() {
+        let service =
+            DateService::new(DateInfoProviderMock::at("2024-12-01T00:00:00Z"));
+        let puzzle = Puzzle::new(2024, 5).unwrap();
+
+        assert_eq!(
+            service.unlock_time(&puzzle).to_rfc3339(),
+            "2024-12-05T00:00:00-05:00"
+        );
+    }
+
+    #[rstest]
+    #[case("2024-12-05T04:59:59Z", Some(Duration::from_secs(1)))]
+    #[case("2024-12-04T05:00:00Z", Some(Duration::from_secs(86400)))]
+    #[case("2024-12-05T04:59:59.250Z", Some(Duration::from_millis(750)))]
+    #[case("2024-12-05T05:00:00Z", None)]
+    #[case("2025-01-01T00:00:00Z", None)]
+    fn time_until_unlock(
+        #[case] now: &str,
+        #[case] expected: Option<Duration>,
+    ) {
+        let service = DateService::new(DateInfoProviderMock::at(now));
+        let puzzle = Puzzle::new(2024, 5).unwrap();
+
+        assert_eq!(service.time_until_unlock(&puzzle), expected);
+    }
+
+    #[test]
+    fn upcoming_event_in_november() {
+        let service = DateService::new(DateInfoProviderMock {
+            now: Cell::new("2025-11-30T12:00:00Z".parse().unwrap()),
+            ..DateInfoProviderMock::new(2025, Month::November, 30)
+        });
+        let puzzle = Puzzle::released(2025, 1, service.latest_year()).unwrap();
+
+        assert_eq!(service.default_year(), 2024);
+        assert_eq!(service.latest_year(), 2025);
+        assert_eq!(
+            service.time_until_unlock(&puzzle),
+            Some(Duration::from_secs(17 * 3600))
+        );
+    }
+
+    #[test]
+    fn wait_for_unlock_ticks_every_second() {
+        let service = DateService::new(DateInfoProviderMock::at(
+            "2024-12-05T04:59:57.500Z",
+        ));
+        let puzzle = Puzzle::new(2024, 5).unwrap();
+        let mut calls = Vec::new();
+
+        service.wait_for_unlock(&puzzle, |remaining, sleep| {
+            calls.push((remaining, sleep));
+            let now = &service.provider.now;
+            now.set(now.get() + sleep);
+        });
+
+        assert_eq!(
+            calls,
+            [
+                (Duration::from_millis(2500), Duration::from_millis(500)),
+                (Duration::from_secs(2), Duration::from_secs(1)),
+                (Duration::from_secs(1), Duration::from_secs(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn wait_for_unlocked_puzzle() {
+        let service =
+            DateService::new(DateInfoProviderMock::at("2024-12-05T05:00:00Z"));
+        let puzzle = Puzzle::new(2024, 5).unwrap();
+
+        service.wait_for_unlock(&puzzle, |remaining, _| {
+            panic!("Waited {:?} for an unlocked puzzle", remaining)
+        });
+    }
+
+    #[rstest]
+    #[case(Duration::from_millis(2500), Duration::from_millis(500))]
+    #[case(Duration::from_secs(3), Duration::from_secs(1))]
+    #[case(Duration::from_millis(300), Duration::from_millis(300))]
+    fn next_tick_is_the_next_whole_second(
+        #[case] remaining: Duration,
+        #[case] expected: Duration,
+    ) {
+        assert_eq!(next_tick(remaining), expected);
+    }
+
+    #[rstest]
+    #[case(Duration::from_secs(59), "00:00:59")]
+    #[case(Duration::from_millis(59_001), "00:01:00")]
+    #[case(Duration::from_secs(3 * 3600 + 12 * 60 + 5), "03:12:05")]
+    #[case(Duration::from_secs(2 * 86400 + 3600), "2d 01:00:00")]
+    fn countdown(#[case] remaining: Duration, #[case] expected: &str) {
+        assert_eq!(format_countdown(remaining), expected);
+    }
 }
@@ -6,15 +6,66 @@
 //!
 //! The main functionality provided by this service includes:
 //! - Extracting a puzzle template and writing it to a file system path based on the
-//! puzzle's year and day.
+//!   puzzle's year and day.
+//! - Registering the generated solver, so the binary can find it.
+//! - Locating the cached puzzle inputs, and migrating them to the canonical
+//!   `yYYYY/dayDD.txt` naming scheme.
+//! - Loading and saving the ledgers of submitted answers.
+//! - Saving the examples found in the puzzle descriptions, and rendering the
+//!   tests checking their answers.
+//! - Loading and saving the user configuration.
+//! - Updating the progress section of the README.
 
-use crate::providers::file_system::{FSExists, FSWrite, LocalFSAdapter};
-use crate::Puzzle;
-use log::{debug, trace};
-use std::io::{Result as IOResult, Write};
-use std::path::PathBuf;
+use crate::answers::{ledger_path, Ledger};
+use crate::config::Config;
+use crate::description::{Description, Example};
+use crate::providers::file_system::{
+    FSExists, FSRead, FSReadDir, FSRename, FSWrite, FSWritePrivate,
+    LocalFSAdapter,
+};
+use crate::{progress, Puzzle};
+use log::{debug, trace, warn};
+use std::collections::BTreeSet;
+use std::io::{Error, ErrorKind, Result as IOResult, Write};
+use std::path::{Path, PathBuf};
 use std::process::exit;
 
+/// Returns the canonical path of the cached input for the given puzzle:
+/// `<inputs_dir>/yYYYY/dayDD.txt`
+pub fn input_path(inputs_dir: &Path, puzzle: &Puzzle) -> PathBuf {
+    inputs_dir
+        .join(format!("y{}", puzzle.year()))
+        .join(format!("day{:02}.txt", puzzle.day()))
+}
+
+/// Returns the path of the cached description of the given puzzle:
+/// `<inputs_dir>/yYYYY/dayDD.html`
+pub fn description_path(inputs_dir: &Path, puzzle: &Puzzle) -> PathBuf {
+    input_path(inputs_dir, puzzle).with_extension("html")
+}
+
+/// Returns the path of the `number`th example of the given puzzle:
+/// `<inputs_dir>/yYYYY/dayDD_NN.example.txt`
+pub fn example_path(
+    inputs_dir: &Path,
+    puzzle: &Puzzle,
+    number: usize,
+) -> PathBuf {
+    inputs_dir.join(format!("y{}", puzzle.year())).join(format!(
+        "day{:02}_{:02}.example.txt",
+        puzzle.day(),
+        number
+    ))
+}
+
+/// Returns the path of the cached private leaderboard of the given year:
+/// `<inputs_dir>/yYYYY/leaderboard_ID.json`
+pub fn leaderboard_path(inputs_dir: &Path, year: u32, id: u64) -> PathBuf {
+    inputs_dir
+        .join(format!("y{}", year))
+        .join(format!("leaderboard_{}.json", id))
+}
+
 /// `FSService` encapsulates file system operations for handling puzzle templates.
 pub struct FSService<F>
 where
@@ -25,14 +76,14 @@ where
 
 impl<F> FSService<F>
 where
-    F: FSWrite + FSExists,
+    F: FSWrite + FSExists + FSRead,
 {
     /// Creates a new instance of `FSService` with the provided file system provider.
     ///
     /// # Arguments
     ///
     /// * `fs_provider` - An instance of the file system provider that implements
-    /// `FileSystem`.
+    ///   `FileSystem`.
     ///
     /// # Returns
     ///
@@ -47,14 +98,20 @@ where
     /// # Inputs
     ///
     /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * tests: Source appended to the template, see [`render_example_tests`].
     ///
     /// # Returns
     ///
     /// An IOResult indicating success or failure of the file operations.
-    pub fn extract_template_for(&self, puzzle: &Puzzle) -> IOResult<()> {
+    pub fn extract_template_for(
+        &self,
+        puzzle: &Puzzle,
+        tests: &str,
+    ) -> IOResult<()> {
         trace!("Extracting template...");
         let template = include_str!("../../templates/day_template.txt")
-            .replace("#DAY", format!("{:02}", puzzle.day()).as_str());
+            .replace("#DAY", format!("{:02}", puzzle.day()).as_str())
+            + tests;
 
         let target: PathBuf = [
             ".",
@@ -83,6 +140,508 @@ where
 
         Ok(())
     }
+
+    /// Registers the solver of the given puzzle, so it can be looked up
+    /// through [`crate::solvers::get_solver`].
+    ///
+    /// The year module is (re)generated with the new day, and the year is
+    /// added to the solvers registry if it was not there yet.
+    ///
+    /// # Inputs
+    ///
+    /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult indicating success or failure of the file operations.
+    pub fn register_solver_for(&self, puzzle: &Puzzle) -> IOResult<()> {
+        trace!("Registering solver...");
+        let solvers_dir: PathBuf = [".", "src", "solvers"].iter().collect();
+
+        // The registry is checked first, so nothing is written without it
+        let registry = solvers_dir.with_extension("rs");
+        let registry_source = self.fs_provider.read_to_string(&registry)?;
+        let registry_source = register_year(&registry_source, puzzle.year())
+            .map_err(|e| {
+                Error::new(e.kind(), format!("{}: {}", registry.display(), e))
+            })?;
+
+        let year_module = solvers_dir.join(format!("y{}.rs", puzzle.year()));
+        let mut days = match self.fs_provider.exists(&year_module) {
+            true => {
+                declared_days(&self.fs_provider.read_to_string(&year_module)?)
+            }
+            false => BTreeSet::new(),
+        };
+
+        if days.insert(puzzle.day()) {
+            debug!("Writing {}...", year_module.display());
+            let mut file = self.fs_provider.open(&year_module)?;
+            write!(file, "{}", render_year_module(puzzle.year(), &days))?;
+        }
+
+        if let Some(source) = registry_source {
+            debug!("Adding y{} to {}...", puzzle.year(), registry.display());
+            let mut file = self.fs_provider.open(&registry)?;
+            write!(file, "{}", source)?;
+        }
+
+        debug!("Registration finished!");
+
+        Ok(())
+    }
+
+    /// Loads the answers ledger of the given year.
+    ///
+    /// # Inputs
+    ///
+    /// * answers_dir: Directory holding the `yYYYY.toml` ledgers.
+    /// * year: The year of the ledger.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult with the ledger, empty if there is no file for the year
+    /// yet. Malformed ledgers are reported as `InvalidData` errors.
+    pub fn load_ledger(
+        &self,
+        answers_dir: &Path,
+        year: u32,
+    ) -> IOResult<Ledger> {
+        let path = ledger_path(answers_dir, year);
+        trace!("Loading answers from {}...", path.display());
+
+        if !self.fs_provider.exists(&path) {
+            return Ok(Ledger::default());
+        }
+
+        let content = self.fs_provider.read_to_string(&path)?;
+
+        toml::from_str(&content).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Writes the answers ledger of the given year, replacing the previous
+    /// one.
+    ///
+    /// # Inputs
+    ///
+    /// * answers_dir: Directory holding the `yYYYY.toml` ledgers.
+    /// * year: The year of the ledger.
+    /// * ledger: The answers to store.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult indicating success or failure of the file operations.
+    pub fn save_ledger(
+        &self,
+        answers_dir: &Path,
+        year: u32,
+        ledger: &Ledger,
+    ) -> IOResult<()> {
+        let path = ledger_path(answers_dir, year);
+        trace!("Saving answers to {}...", path.display());
+
+        let content = toml::to_string(ledger)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        let mut file = self.fs_provider.open(&path)?;
+        write!(file, "{}", content)?;
+
+        Ok(())
+    }
+
+    /// Loads a cached puzzle description.
+    ///
+    /// # Inputs
+    ///
+    /// * path: The cached description, or a page downloaded from AoC.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult with the description, or `None` if the file does not
+    /// exist.
+    pub fn load_description(
+        &self,
+        path: &Path,
+    ) -> IOResult<Option<Description>> {
+        trace!("Loading description from {}...", path.display());
+
+        if !self.fs_provider.exists(path) {
+            return Ok(None);
+        }
+
+        let html = self.fs_provider.read_to_string(path)?;
+
+        Ok(Some(Description::from_html(&html)))
+    }
+
+    /// Loads the user configuration.
+    ///
+    /// # Inputs
+    ///
+    /// * path: The configuration file, see
+    ///   [`default_config_path`](crate::config::default_config_path).
+    ///
+    /// # Returns
+    ///
+    /// An IOResult with the configuration, empty if the file does not exist
+    /// yet. Malformed configurations are reported as `InvalidData` errors.
+    pub fn load_config(&self, path: &Path) -> IOResult<Config> {
+        trace!("Loading configuration from {}...", path.display());
+
+        if !self.fs_provider.exists(path) {
+            return Ok(Config::default());
+        }
+
+        let content = self.fs_provider.read_to_string(path)?;
+
+        toml::from_str(&content).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Rewrites the progress section of a Markdown file, such as the README.
+    ///
+    /// # Inputs
+    ///
+    /// * path: The file holding the section.
+    /// * content: The new content of the section, see
+    ///   [`render_markdown`](crate::progress::render_markdown).
+    ///
+    /// # Returns
+    ///
+    /// An IOResult with whether the file has a section delimited by the
+    /// [`progress`](crate::progress) markers. The file is left untouched
+    /// when it does not.
+    pub fn update_progress_section(
+        &self,
+        path: &Path,
+        content: &str,
+    ) -> IOResult<bool> {
+        trace!("Updating the progress section of {}...", path.display());
+
+        let text = self.fs_provider.read_to_string(path)?;
+        let Some(updated) = progress::replace_section(&text, content) else {
+            return Ok(false);
+        };
+
+        if updated != text {
+            let mut file = self.fs_provider.open(path)?;
+            write!(file, "{}", updated)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Saves the examples of a puzzle as `dayDD_NN.example.txt` files,
+    /// numbered from 1 in order of appearance.
+    ///
+    /// # Inputs
+    ///
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * examples: The content of each example.
+    /// * force: Whether to overwrite the examples that already exist.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult with the paths of the written files. Existing examples
+    /// are kept, unless `force` is set, as they may have been edited.
+    pub fn save_examples(
+        &self,
+        inputs_dir: &Path,
+        puzzle: &Puzzle,
+        examples: &[String],
+        force: bool,
+    ) -> IOResult<Vec<PathBuf>> {
+        let mut written = Vec::new();
+
+        for (i, example) in examples.iter().enumerate() {
+            let path = example_path(inputs_dir, puzzle, i + 1);
+
+            if !force && self.fs_provider.exists(&path) {
+                debug!("Keeping the existing {}", path.display());
+                continue;
+            }
+
+            trace!("Saving example to {}...", path.display());
+            let mut file = self.fs_provider.open(&path)?;
+            write!(file, "{}", example)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+}
+
+/// Returns the days declared as `pub mod dayNN;` in a year module
+fn declared_days(source: &str) -> BTreeSet<u32> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub mod day"))
+        .filter_map(|line| line.strip_suffix(';'))
+        .filter_map(|day| day.parse().ok())
+        .collect()
+}
+
+/// Renders the module of a year, declaring and registering the given days
+fn render_year_module(year: u32, days: &BTreeSet<u32>) -> String {
+    let modules: String = days
+        .iter()
+        .map(|day| format!("pub mod day{:02};\n", day))
+        .collect();
+    let entries: String = days
+        .iter()
+        .map(|day| format!("    ({}, day{:02}::solve),\n", day, day))
+        .collect();
+
+    format!(
+        "//! Advent of Code challenges for the year {year}
+//!
+//! This file is maintained by `aoc generate`.
+use crate::solvers::SolveFn;
+
+{modules}
+/// Registered solvers for {year}, indexed by day
+#[rustfmt::skip]
+pub const SOLVERS: &[(u32, SolveFn)] = &[
+{entries}];
+"
+    )
+}
+
+/// Renders the tests module checking the answers of the examples, to be
+/// appended to the solver of the puzzle. The examples are read from the
+/// files written by [`FSService::save_examples`].
+///
+/// Returns an empty string when no example has an answer.
+pub fn render_example_tests(
+    inputs_dir: &Path,
+    puzzle: &Puzzle,
+    examples: &[Example],
+) -> String {
+    let mut constants = String::new();
+    let mut tests = String::new();
+
+    for (i, example) in examples.iter().enumerate() {
+        let number = i + 1;
+        if example.answers.iter().all(Option::is_none) {
+            continue;
+        }
+
+        let path = example_path(inputs_dir, puzzle, number);
+        let path = path.to_string_lossy().replace('\\', "/");
+        let source = match inputs_dir.is_absolute() {
+            true => format!("{:?}", path),
+            false => format!(
+                "concat!(\n        env!(\"CARGO_MANIFEST_DIR\"),\n        \
+                 {:?}\n    )",
+                format!("/{}", path)
+            ),
+        };
+        constants.push_str(&format!(
+            "    const EXAMPLE_{number:02}: &str = include_str!({source});\n"
+        ));
+
+        for (part, answer) in example.answers.iter().enumerate() {
+            let Some(answer) = answer else {
+                continue;
+            };
+            let level = part + 1;
+
+            tests.push_str(&format!(
+                "
+    #[test]
+    fn test_example_{number:02}_p{level}() {{
+        let report = solve(Execute::P{level}, EXAMPLE_{number:02});
+
+        assert_eq!(report.solutions[{part}].to_string(), {answer:?});
+    }}
+"
+            ));
+        }
+    }
+
+    if tests.is_empty() {
+        return tests;
+    }
+
+    format!(
+        "
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+{constants}{tests}}}
+"
+    )
+}
+
+/// Adds the given year to the source of the solvers registry.
+///
+/// Returns `None` when the year is already registered, and an
+/// `InvalidData` error when the registry cannot be found in the source.
+fn register_year(source: &str, year: u32) -> IOResult<Option<String>> {
+    let module = format!("pub mod y{};", year);
+    let entry = format!("    ({}, y{}::SOLVERS),", year, year);
+    let mut lines: Vec<&str> = source.lines().collect();
+
+    if lines.contains(&module.as_str()) {
+        return Ok(None);
+    }
+
+    let not_found = |what: &str| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("{} not found in the solvers registry", what),
+        )
+    };
+    let registry_start = lines
+        .iter()
+        .position(|l| l.starts_with("const REGISTRY"))
+        .ok_or_else(|| not_found("`const REGISTRY`"))?;
+    let registry_end = registry_start
+        + lines[registry_start..]
+            .iter()
+            .position(|l| *l == "];")
+            .ok_or_else(|| not_found("The closing `];` of `REGISTRY`"))?;
+    let entry_position = (registry_start + 1..registry_end)
+        .find(|&i| lines[i] > entry.as_str())
+        .unwrap_or(registry_end);
+    lines.insert(entry_position, &entry);
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod y"))
+        .collect();
+    let module_position = modules
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > module.as_str())
+        .or(modules.last().map(|i| i + 1))
+        .ok_or_else(|| not_found("`pub mod yYYYY;`"))?;
+    lines.insert(module_position, &module);
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+impl<F> FSService<F>
+where
+    F: FSWritePrivate + FSExists + FSRead,
+{
+    /// Writes the user configuration, replacing the previous one. The file
+    /// is only readable by its owner, as it holds the session cookie.
+    ///
+    /// # Inputs
+    ///
+    /// * path: The configuration file.
+    /// * config: The settings to store.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult indicating success or failure of the file operations.
+    pub fn save_config(&self, path: &Path, config: &Config) -> IOResult<()> {
+        trace!("Saving configuration to {}...", path.display());
+
+        let content = toml::to_string(config)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        let mut file = self.fs_provider.open_private(path)?;
+        write!(file, "{}", content)?;
+
+        Ok(())
+    }
+}
+
+impl<F> FSService<F>
+where
+    F: FSWrite + FSExists + FSReadDir + FSRename,
+{
+    /// Renames the cached inputs of every `yYYYY` directory inside
+    /// `inputs_dir` to the canonical naming scheme:
+    ///
+    /// * `NN.txt` becomes `dayNN.txt`
+    /// * `NN.example.txt` becomes `dayNN_01.example.txt`
+    /// * `NN_MM.example.txt` becomes `dayNN_MM.example.txt`
+    ///
+    /// Files already following the scheme are left untouched, and so are
+    /// the ones whose canonical name is already taken.
+    ///
+    /// # Returns
+    ///
+    /// An IOResult with the `(old, new)` paths of every renamed file.
+    pub fn migrate_inputs(
+        &self,
+        inputs_dir: &Path,
+    ) -> IOResult<Vec<(PathBuf, PathBuf)>> {
+        trace!("Migrating inputs in {}...", inputs_dir.display());
+        let mut renamed = Vec::new();
+
+        for year_dir in self.fs_provider.read_dir(inputs_dir)? {
+            let is_year_dir = year_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix('y'))
+                .is_some_and(|year| year.parse::<u32>().is_ok());
+
+            if !is_year_dir {
+                debug!("Skipping {}...", year_dir.display());
+                continue;
+            }
+
+            for file in self.fs_provider.read_dir(&year_dir)? {
+                let Some(name) = file.file_name().and_then(|n| n.to_str())
+                else {
+                    continue;
+                };
+                let Some(canonical) = canonical_input_name(name) else {
+                    continue;
+                };
+                let target = year_dir.join(canonical);
+
+                if self.fs_provider.exists(&target) {
+                    warn!(
+                        "Not renaming {}: {} already exists",
+                        file.display(),
+                        target.display()
+                    );
+                    continue;
+                }
+
+                self.fs_provider.rename(&file, &target)?;
+                renamed.push((file, target));
+            }
+        }
+
+        Ok(renamed)
+    }
+}
+
+/// Returns the canonical name of a cached input file, or `None` if the
+/// name is already canonical or not recognised
+fn canonical_input_name(name: &str) -> Option<String> {
+    let (stem, extension) = name.split_once('.')?;
+    let (day, part) = match stem.split_once('_') {
+        Some((day, part)) => (day, Some(part)),
+        None => (stem, None),
+    };
+
+    if day.len() != 2 || !day.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    match (extension, part) {
+        ("txt", None) => Some(format!("day{}.txt", day)),
+        ("example.txt", None) => Some(format!("day{}_01.example.txt", day)),
+        ("example.txt", Some(part)) => {
+            Some(format!("day{}_{}.example.txt", day, part))
+        }
+        _ => None,
+    }
 }
 
 impl Default for FSService<LocalFSAdapter> {
@@ -91,3 +650,380 @@ impl Default for FSService<LocalFSAdapter> {
         Self::new(LocalFSAdapter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::file_system::tests::FSMock;
+    use crate::services::http::SubmitOutcome;
+    use crate::Part;
+
+    const REGISTRY_SOURCE: &str = "use std::path::Path;
+
+pub mod y2023;
+pub mod y2024;
+
+#[rustfmt::skip]
+const REGISTRY: &[(u32, &[(u32, SolveFn)])] = &[
+    (2023, y2023::SOLVERS),
+    (2024, y2024::SOLVERS),
+];
+";
+
+    #[test]
+    fn test_declared_days() {
+        let source =
+            "use crate::solvers::SolveFn;\n\npub mod day01;\npub mod day12;\n";
+
+        assert_eq!(declared_days(source), BTreeSet::from([1, 12]));
+    }
+
+    #[test]
+    fn test_render_year_module_round_trip() {
+        let days = BTreeSet::from([1, 2, 10]);
+        let source = render_year_module(2015, &days);
+
+        assert!(source.contains("pub mod day10;\n"));
+        assert!(source.contains("    (10, day10::solve),\n"));
+        assert_eq!(declared_days(&source), days);
+    }
+
+    #[test]
+    fn test_register_year_appends_new_year() {
+        let result = register_year(REGISTRY_SOURCE, 2025).unwrap().unwrap();
+
+        assert!(result.contains("pub mod y2024;\npub mod y2025;\n"));
+        assert!(result.contains(
+            "    (2024, y2024::SOLVERS),\n    (2025, y2025::SOLVERS),\n];"
+        ));
+    }
+
+    #[test]
+    fn test_register_year_keeps_order() {
+        let result = register_year(REGISTRY_SOURCE, 2015).unwrap().unwrap();
+
+        assert!(result.contains("pub mod y2015;\npub mod y2023;\n"));
+        assert!(result.contains(
+            "= &[\n    (2015, y2015::SOLVERS),\n    (2023, y2023::SOLVERS),"
+        ));
+    }
+
+    #[test]
+    fn test_register_year_matches_solvers_registry() {
+        let source = include_str!("../solvers.rs");
+
+        assert!(register_year(source, 2015).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_register_year_already_registered() {
+        assert_eq!(register_year(REGISTRY_SOURCE, 2023).unwrap(), None);
+    }
+
+    #[test]
+    fn test_register_year_without_registry() {
+        let source = REGISTRY_SOURCE.replace("const REGISTRY", "const SOLVERS");
+        let err = register_year(&source, 2025).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(err.to_string().contains("`const REGISTRY`"));
+    }
+
+    #[test]
+    fn test_register_year_without_registry_end() {
+        let source = REGISTRY_SOURCE.replace("];", "]");
+        let err = register_year(&source, 2025).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_register_solver_without_registry_writes_nothing() {
+        let solvers_dir: PathBuf = [".", "src", "solvers"].iter().collect();
+        let mut fs_mock = FSMock::new();
+        fs_mock
+            .insert_file(&solvers_dir.with_extension("rs"), "pub mod y2024;\n");
+        let service = FSService::new(fs_mock);
+
+        let err = service
+            .register_solver_for(&Puzzle::new(2025, 1).unwrap())
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            service.fs_provider.file(&solvers_dir.join("y2025.rs")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_input_path() {
+        let puzzle = Puzzle::new(2024, 3).unwrap();
+        let expected: PathBuf =
+            ["inputs", "y2024", "day03.txt"].iter().collect();
+
+        assert_eq!(input_path(Path::new("inputs"), &puzzle), expected);
+    }
+
+    #[test]
+    fn test_description_and_example_paths() {
+        let puzzle = Puzzle::new(2024, 3).unwrap();
+        let description: PathBuf =
+            ["inputs", "y2024", "day03.html"].iter().collect();
+        let example: PathBuf =
+            ["inputs", "y2024", "day03_02.example.txt"].iter().collect();
+
+        assert_eq!(description_path(Path::new("inputs"), &puzzle), description);
+        assert_eq!(example_path(Path::new("inputs"), &puzzle, 2), example);
+    }
+
+    #[test]
+    fn test_leaderboard_path() {
+        let expected: PathBuf =
+            ["inputs", "y2024", "leaderboard_42.json"].iter().collect();
+
+        assert_eq!(leaderboard_path(Path::new("inputs"), 2024, 42), expected);
+    }
+
+    #[test]
+    fn test_canonical_input_name() {
+        assert_eq!(canonical_input_name("07.txt").unwrap(), "day07.txt");
+        assert_eq!(
+            canonical_input_name("07.example.txt").unwrap(),
+            "day07_01.example.txt"
+        );
+        assert_eq!(
+            canonical_input_name("07_02.example.txt").unwrap(),
+            "day07_02.example.txt"
+        );
+    }
+
+    #[test]
+    fn test_canonical_input_name_leaves_other_files() {
+        assert_eq!(canonical_input_name("day07.txt"), None);
+        assert_eq!(canonical_input_name("day07_01.example.txt"), None);
+        assert_eq!(canonical_input_name("7.txt"), None);
+        assert_eq!(canonical_input_name("07.md"), None);
+        assert_eq!(canonical_input_name("README"), None);
+    }
+
+    #[test]
+    fn test_ledger_round_trip() {
+        let answers_dir = tempfile::tempdir().unwrap();
+        let service = FSService::default();
+        let mut ledger = service.load_ledger(answers_dir.path(), 2024).unwrap();
+
+        assert_eq!(ledger, Ledger::default());
+
+        ledger.record(1, Part::P1, "42", SubmitOutcome::Right);
+        service
+            .save_ledger(answers_dir.path(), 2024, &ledger)
+            .unwrap();
+
+        assert!(answers_dir.path().join("y2024.toml").is_file());
+        assert_eq!(
+            service.load_ledger(answers_dir.path(), 2024).unwrap(),
+            ledger
+        );
+    }
+
+    #[test]
+    fn test_malformed_ledger() {
+        let answers_dir = tempfile::tempdir().unwrap();
+        std::fs::write(answers_dir.path().join("y2024.toml"), "[day01")
+            .unwrap();
+
+        let err = FSService::default()
+            .load_ledger(answers_dir.path(), 2024)
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_update_progress_section() {
+        let path = Path::new("README.md");
+        let mut fs_mock = FSMock::new();
+        fs_mock.insert_file(
+            path,
+            &format!(
+                "# AoC\n{}\nold\n{}\n",
+                progress::SECTION_START,
+                progress::SECTION_END
+            ),
+        );
+        let service = FSService::new(fs_mock);
+
+        assert!(service.update_progress_section(path, "new").unwrap());
+        assert_eq!(
+            service.fs_provider.file(path).unwrap(),
+            format!(
+                "# AoC\n{}\nnew\n{}\n",
+                progress::SECTION_START,
+                progress::SECTION_END
+            )
+        );
+    }
+
+    #[test]
+    fn test_update_missing_progress_section() {
+        let path = Path::new("README.md");
+        let mut fs_mock = FSMock::new();
+        fs_mock.insert_file(path, "# AoC\n");
+        let service = FSService::new(fs_mock);
+
+        assert!(!service.update_progress_section(path, "new").unwrap());
+        assert_eq!(service.fs_provider.file(path).unwrap(), "# AoC\n");
+    }
+
+    #[test]
+    fn test_config_round_trip() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let path = config_dir.path().join("aoc").join("config.toml");
+        let service = FSService::default();
+
+        assert_eq!(service.load_config(&path).unwrap(), Config::default());
+
+        let config = Config {
+            session: Some("53616c74".to_string()),
+        };
+        service.save_config(&path, &config).unwrap();
+
+        assert_eq!(service.load_config(&path).unwrap(), config);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_config_is_saved_privately() {
+        let path = Path::new("aoc/config.toml");
+        let service = FSService::new(FSMock::new());
+        let config = Config {
+            session: Some("53616c74".to_string()),
+        };
+
+        service.save_config(path, &config).unwrap();
+
+        assert!(service.fs_provider.is_private(path));
+        assert_eq!(
+            service.fs_provider.file(path),
+            Some("session = \"53616c74\"\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_existing_config_is_restricted() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let path = config_dir.path().join("config.toml");
+        std::fs::write(&path, "").unwrap();
+
+        FSService::default()
+            .save_config(&path, &Config::default())
+            .unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_malformed_config() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let path = config_dir.path().join("config.toml");
+        std::fs::write(&path, "session = ").unwrap();
+
+        let err = FSService::default().load_config(&path).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_render_example_tests() {
+        let puzzle = Puzzle::new(2024, 3).unwrap();
+        let examples = [
+            Example::default(),
+            Example {
+                input: "abc\n".to_string(),
+                answers: [Some("4".to_string()), Some("5".to_string())],
+            },
+        ];
+
+        assert_eq!(
+            render_example_tests(Path::new("inputs"), &puzzle, &examples),
+            r#"
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_02: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2024/day03_02.example.txt"
+    ));
+
+    #[test]
+    fn test_example_02_p1() {
+        let report = solve(Execute::P1, EXAMPLE_02);
+
+        assert_eq!(report.solutions[0].to_string(), "4");
+    }
+
+    #[test]
+    fn test_example_02_p2() {
+        let report = solve(Execute::P2, EXAMPLE_02);
+
+        assert_eq!(report.solutions[1].to_string(), "5");
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_example_tests_without_answers() {
+        let puzzle = Puzzle::new(2024, 3).unwrap();
+
+        assert_eq!(
+            render_example_tests(
+                Path::new("inputs"),
+                &puzzle,
+                &[Example::default()]
+            ),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_save_examples_keeps_existing_ones() {
+        let puzzle = Puzzle::new(2024, 3).unwrap();
+        let inputs_dir = tempfile::tempdir().unwrap();
+        let examples = ["a\n".to_string(), "b\n".to_string()];
+        let first = example_path(inputs_dir.path(), &puzzle, 1);
+        std::fs::create_dir_all(first.parent().unwrap()).unwrap();
+        std::fs::write(&first, "edited\n").unwrap();
+
+        let service = FSService::default();
+        let written = service
+            .save_examples(inputs_dir.path(), &puzzle, &examples, false)
+            .unwrap();
+
+        assert_eq!(written, [example_path(inputs_dir.path(), &puzzle, 2)]);
+        assert_eq!(std::fs::read_to_string(&first).unwrap(), "edited\n");
+        assert_eq!(std::fs::read_to_string(&written[0]).unwrap(), "b\n");
+
+        let written = service
+            .save_examples(inputs_dir.path(), &puzzle, &examples, true)
+            .unwrap();
+
+        assert_eq!(written.len(), 2);
+        assert_eq!(std::fs::read_to_string(&first).unwrap(), "a\n");
+    }
+}
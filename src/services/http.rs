@@ -1,42 +1,262 @@
+use crate::constants::LEADERBOARD_CACHE_TTL;
+use crate::description::Description;
+use crate::leaderboard::Leaderboard;
+use crate::providers::file_system::{
+    FSExists, FSModified, FSRead, FSWrite, LocalFSAdapter,
+};
 use crate::providers::http::{HTTPAdapter, HTTPError, HTTPProvider, URL};
-use crate::Puzzle;
-use log::{debug, trace};
+use crate::services::fs::{description_path, input_path, leaderboard_path};
+use crate::{Part, Puzzle};
+use exitcode::ExitCode;
+use log::{debug, trace, warn};
+use scraper::{Html, Selector};
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
 
-pub struct HTTPService<H>
+/// What AoC answered to a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was sent too recently, the next one can be sent after
+    /// the given time
+    RateLimited(Duration),
+    /// The part was already solved, or it is not unlocked yet
+    WrongLevel,
+}
+
+impl SubmitOutcome {
+    /// Parses the HTML page AoC returns after submitting an answer
+    pub fn from_html(html: &str) -> Result<Self, HTTPError> {
+        if html.contains("That's the right answer") {
+            return Ok(Self::Right);
+        }
+
+        if html.contains("You gave an answer too recently") {
+            return Ok(Self::RateLimited(parse_wait_time(html)));
+        }
+
+        if html.contains("You don't seem to be solving the right level") {
+            return Ok(Self::WrongLevel);
+        }
+
+        if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                return Ok(Self::TooHigh);
+            }
+
+            if html.contains("your answer is too low") {
+                return Ok(Self::TooLow);
+            }
+
+            return Ok(Self::Wrong);
+        }
+
+        Err(HTTPError::ParseError(
+            "unexpected response to the submitted answer".into(),
+        ))
+    }
+
+    /// Returns the exit code matching the outcome
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            Self::Right => exitcode::OK,
+            Self::Wrong | Self::TooHigh | Self::TooLow => exitcode::DATAERR,
+            Self::RateLimited(_) => exitcode::TEMPFAIL,
+            Self::WrongLevel => exitcode::UNAVAILABLE,
+        }
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "That's the right answer!"),
+            Self::Wrong => write!(f, "That's not the right answer"),
+            Self::TooHigh => {
+                write!(f, "That's not the right answer: it is too high")
+            }
+            Self::TooLow => {
+                write!(f, "That's not the right answer: it is too low")
+            }
+            Self::RateLimited(wait) => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again",
+                wait.as_secs()
+            ),
+            Self::WrongLevel => write!(
+                f,
+                "This part is already solved, or it is not unlocked yet"
+            ),
+        }
+    }
+}
+
+/// Extracts the wait time from sentences like `You have 1m 5s left to
+/// wait`. Defaults to zero when it is not found.
+fn parse_wait_time(html: &str) -> Duration {
+    let Some(start) = html.find("You have ") else {
+        return Duration::ZERO;
+    };
+    let rest = &html[start + "You have ".len()..];
+    let Some(end) = rest.find(" left to wait") else {
+        return Duration::ZERO;
+    };
+
+    let seconds = rest[..end]
+        .split_whitespace()
+        .filter_map(|token| {
+            let unit = token.chars().last()?;
+            let value: u64 = token[..token.len() - 1].parse().ok()?;
+
+            match unit {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                's' => Some(value),
+                _ => None,
+            }
+        })
+        .sum();
+
+    Duration::from_secs(seconds)
+}
+
+pub struct HTTPService<H, F>
 where
     H: HTTPProvider,
+    F: FSWrite + FSExists + FSRead,
 {
     http_provider: H,
+    fs_provider: F,
 }
 
-impl<H> HTTPService<H>
+impl<H, F> HTTPService<H, F>
 where
     H: HTTPProvider,
+    F: FSWrite + FSExists + FSRead,
 {
     /// Creates a new instance of HTTPService.
     ///
     /// # Inputs
     ///
     /// * http_provider: An instance of a type that implements HTTPProvider.
+    /// * fs_provider: The file system where the downloaded inputs are cached.
     ///
     /// # Returns
     ///
     ///  A new HTTPService instance.
-    pub fn new(http_provider: H) -> Self {
-        Self { http_provider }
+    pub fn new(http_provider: H, fs_provider: F) -> Self {
+        Self {
+            http_provider,
+            fs_provider,
+        }
+    }
+
+    /// Reads the cached input of a given Advent of Code puzzle, without
+    /// fetching it.
+    ///
+    /// # Inputs
+    ///
+    /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the cached input, if any, or an HTTPError when
+    /// the cache cannot be read.
+    pub fn cached_input(
+        &self,
+        puzzle: &Puzzle,
+        inputs_dir: &Path,
+    ) -> Result<Option<String>, HTTPError> {
+        let cache = input_path(inputs_dir, puzzle);
+
+        if !self.fs_provider.exists(&cache) {
+            return Ok(None);
+        }
+
+        debug!("Using the cached input at {}", cache.display());
+
+        self.fs_provider
+            .read_to_string(&cache)
+            .map(Some)
+            .map_err(|e| {
+                HTTPError::CacheError(format!("{}: {}", cache.display(), e))
+            })
+    }
+
+    /// Reads the cached description of a given Advent of Code puzzle,
+    /// without fetching it.
+    ///
+    /// # Inputs
+    ///
+    /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * part: The part that should be described, if any.
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the cached description, if any and it describes
+    /// `part`, or an HTTPError when the cache cannot be read.
+    pub fn cached_description(
+        &self,
+        puzzle: &Puzzle,
+        part: Option<Part>,
+        inputs_dir: &Path,
+    ) -> Result<Option<Description>, HTTPError> {
+        let cache = description_path(inputs_dir, puzzle);
+
+        if !self.fs_provider.exists(&cache) {
+            return Ok(None);
+        }
+
+        let html = self.fs_provider.read_to_string(&cache).map_err(|e| {
+            HTTPError::CacheError(format!("{}: {}", cache.display(), e))
+        })?;
+        let description = Description::from_html(&html);
+
+        if part.is_some_and(|part| !description.has(part)) {
+            return Ok(None);
+        }
+
+        debug!("Using the cached description at {}", cache.display());
+
+        Ok(Some(description))
     }
 
     /// Downloads the input for a given Advent of Code puzzle.
     ///
+    /// The input is cached in `inputs_dir`, and later calls return the
+    /// cached copy instead of fetching it again, unless `force` is set.
+    ///
     /// # Inputs
     ///
     /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    /// * force: Whether to fetch the input even if it is already cached.
     ///
     /// # Returns
     ///
     /// A Result containing the puzzle input text if successful,
     /// or an HTTPError otherwise.
-    pub fn download_input(&self, puzzle: &Puzzle) -> Result<String, HTTPError> {
+    pub fn download_input(
+        &self,
+        puzzle: &Puzzle,
+        inputs_dir: &Path,
+        force: bool,
+    ) -> Result<String, HTTPError> {
+        if !force {
+            if let Some(input) = self.cached_input(puzzle, inputs_dir)? {
+                return Ok(input);
+            }
+        }
+
+        let cache = input_path(inputs_dir, puzzle);
+
         trace!(
             "Downloading puzzle year: {}, day: {}",
             puzzle.year,
@@ -50,71 +270,752 @@ where
         let response = self.http_provider.get(&url)?;
 
         debug!("response: {:?}", response);
-        trace!("parsing text and returning");
+        trace!("caching the input at {}", cache.display());
+
+        self.fs_provider
+            .open(&cache)
+            .and_then(|mut file| write!(file, "{}", response))
+            .map_err(|e| {
+                HTTPError::CacheError(format!("{}: {}", cache.display(), e))
+            })?;
 
         Ok(response)
     }
 
+    /// Downloads the description of a given Advent of Code puzzle.
+    ///
+    /// The description is cached in `inputs_dir`, next to the input. The
+    /// cached copy is used unless `force` is set, or `part` is not in it
+    /// yet: part 2 is only published once part 1 is solved.
+    ///
+    /// # Inputs
+    ///
+    /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * part: The part that should be described, if any.
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    /// * force: Whether to fetch the description even if it is already cached.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the description of the unlocked parts if
+    /// successful, or an HTTPError otherwise.
+    pub fn download_description(
+        &self,
+        puzzle: &Puzzle,
+        part: Option<Part>,
+        inputs_dir: &Path,
+        force: bool,
+    ) -> Result<Description, HTTPError> {
+        if !force {
+            if let Some(description) =
+                self.cached_description(puzzle, part, inputs_dir)?
+            {
+                return Ok(description);
+            }
+        }
+
+        let cache = description_path(inputs_dir, puzzle);
+
+        trace!(
+            "Downloading description of puzzle year: {}, day: {}",
+            puzzle.year,
+            puzzle.day
+        );
+
+        let url = URL::puzzle(puzzle);
+
+        debug!("endpoint: {:?}", url);
+
+        let description =
+            Description::from_html(&self.http_provider.get(&url)?);
+
+        if description.is_empty() {
+            return Err(HTTPError::ParseError(
+                "the page has no puzzle description".into(),
+            ));
+        }
+
+        trace!("caching the description at {}", cache.display());
+
+        self.fs_provider
+            .open(&cache)
+            .and_then(|mut file| write!(file, "{}", description.to_html()))
+            .map_err(|e| {
+                HTTPError::CacheError(format!("{}: {}", cache.display(), e))
+            })?;
+
+        Ok(description)
+    }
+
+    /// Submits the answer of a part of an Advent of Code puzzle.
+    ///
+    /// # Inputs
+    ///
+    /// * puzzle: A reference to a Puzzle containing the year and day of the puzzle.
+    /// * part: The part the answer belongs to.
+    /// * answer: The answer, as it should be typed in the website.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing what AoC said about the answer if successful,
+    /// or an HTTPError otherwise.
+    pub fn submit_answer(
+        &self,
+        puzzle: &Puzzle,
+        part: Part,
+        answer: &str,
+    ) -> Result<SubmitOutcome, HTTPError> {
+        trace!(
+            "Submitting {} for puzzle year: {}, day: {}, part: {}",
+            answer,
+            puzzle.year,
+            puzzle.day,
+            part
+        );
+
+        let url = URL::answer(puzzle);
+        let level = part.level().to_string();
+
+        debug!("endpoint: {:?}", url);
+
+        let response = self
+            .http_provider
+            .post(&url, &[("level", &level), ("answer", answer)])?;
+
+        debug!("response: {:?}", response);
+
+        SubmitOutcome::from_html(&response)
+    }
+
+    /// Checks the session cookie by loading a page only available to logged
+    /// in users.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the name of the logged in user, as shown in the
+    /// header of the page, or [`HTTPError::ExpiredCookie`] when AoC does not
+    /// recognize the session.
+    pub fn check_session(&self) -> Result<String, HTTPError> {
+        let url = URL::settings();
+
+        debug!("endpoint: {:?}", url);
+
+        let response = self.http_provider.get(&url)?;
+        let selector = Selector::parse("header div.user").unwrap();
+        let document = Html::parse_document(&response);
+
+        document
+            .select(&selector)
+            .next()
+            .and_then(|user| user.text().next())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .ok_or(HTTPError::ExpiredCookie)
+    }
+
     /// Internally calls the [`set_cookie`] method of the [`HTTPProvider`]
     pub fn set_cookie(&mut self, cookie: String) {
         self.http_provider.set_cookie(cookie);
     }
+
+    /// Internally calls the [`set_user_agent`] method of the [`HTTPProvider`]
+    pub fn set_user_agent(&mut self, user_agent: String) {
+        self.http_provider.set_user_agent(user_agent);
+    }
 }
 
-impl Default for HTTPService<HTTPAdapter> {
-    /// Returns an HTTPService already configured with [`HTTPAdapter`]
+impl<H, F> HTTPService<H, F>
+where
+    H: HTTPProvider,
+    F: FSWrite + FSExists + FSRead + FSModified,
+{
+    /// Reads the cached copy of a private leaderboard, without fetching it.
+    ///
+    /// # Inputs
+    ///
+    /// * year: The year of the leaderboard.
+    /// * id: The id of the leaderboard, as shown in its URL.
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the cached leaderboard, if any and recent enough,
+    /// or an HTTPError when the cache cannot be read.
+    pub fn cached_leaderboard(
+        &self,
+        year: u32,
+        id: u64,
+        inputs_dir: &Path,
+    ) -> Result<Option<Leaderboard>, HTTPError> {
+        let cache = leaderboard_path(inputs_dir, year, id);
+
+        if !self.is_fresh(&cache) {
+            return Ok(None);
+        }
+
+        debug!("Using the cached leaderboard at {}", cache.display());
+        let json = self.fs_provider.read_to_string(&cache).map_err(|e| {
+            HTTPError::CacheError(format!("{}: {}", cache.display(), e))
+        })?;
+
+        match Leaderboard::from_json(&json) {
+            Ok(leaderboard) => Ok(Some(leaderboard)),
+            Err(e) => {
+                warn!("Ignoring the cached leaderboard: {}", e);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Downloads a private leaderboard.
+    ///
+    /// AoC asks not to request it more than once every 15 minutes, so the
+    /// response is cached in `inputs_dir` and reused while it is recent
+    /// enough, unless `force` is set.
+    ///
+    /// # Inputs
+    ///
+    /// * year: The year of the leaderboard.
+    /// * id: The id of the leaderboard, as shown in its URL.
+    /// * inputs_dir: Directory where the puzzle inputs are cached.
+    /// * force: Whether to fetch the leaderboard even if it is recent.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the leaderboard if successful, or an HTTPError
+    /// otherwise.
+    pub fn download_leaderboard(
+        &self,
+        year: u32,
+        id: u64,
+        inputs_dir: &Path,
+        force: bool,
+    ) -> Result<Leaderboard, HTTPError> {
+        if !force {
+            if let Some(leaderboard) =
+                self.cached_leaderboard(year, id, inputs_dir)?
+            {
+                return Ok(leaderboard);
+            }
+        }
+
+        let cache = leaderboard_path(inputs_dir, year, id);
+
+        trace!("Downloading leaderboard {} of {}", id, year);
+
+        let url = URL::leaderboard(year, id);
+
+        debug!("endpoint: {:?}", url);
+
+        let json = self.http_provider.get(&url)?;
+        // AoC answers with the HTML of the leaderboards page when the cookie
+        // cannot see the leaderboard
+        let leaderboard = Leaderboard::from_json(&json).map_err(|e| {
+            HTTPError::ParseError(format!(
+                "{}. Is the cookie a member of the leaderboard?",
+                e
+            ))
+        })?;
+
+        trace!("caching the leaderboard at {}", cache.display());
+
+        self.fs_provider
+            .open(&cache)
+            .and_then(|mut file| write!(file, "{}", json))
+            .map_err(|e| {
+                HTTPError::CacheError(format!("{}: {}", cache.display(), e))
+            })?;
+
+        Ok(leaderboard)
+    }
+
+    /// Returns whether the file exists and it was written recently enough
+    /// to be used instead of requesting it again
+    fn is_fresh(&self, cache: &Path) -> bool {
+        if !self.fs_provider.exists(cache) {
+            return false;
+        }
+
+        self.fs_provider
+            .modified(cache)
+            .ok()
+            .and_then(|modified| {
+                SystemTime::now().duration_since(modified).ok()
+            })
+            .is_some_and(|age| age < LEADERBOARD_CACHE_TTL)
+    }
+}
+
+impl Default for HTTPService<HTTPAdapter, LocalFSAdapter> {
+    /// Returns an HTTPService already configured with [`HTTPAdapter`] and
+    /// [`LocalFSAdapter`]
     fn default() -> Self {
-        Self::new(HTTPAdapter::default())
+        Self::new(HTTPAdapter::default(), LocalFSAdapter)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::providers::file_system::tests::FSMock;
     use crate::providers::http::tests::HttpProviderMock;
+    use std::path::PathBuf;
     mod download_input {
         use super::super::*;
         use super::*;
         use crate::providers::http::HTTPError;
         use crate::Puzzle;
 
+        const ENDPOINT: &str = "https://adventofcode.com/2023/day/5/input";
+
+        fn cache() -> PathBuf {
+            ["inputs", "y2023", "day05.txt"].iter().collect()
+        }
+
         #[test]
         fn happy_path() {
             let puzzle = Puzzle::new(2023, 5).unwrap();
-            let expected_endpoint = "https://adventofcode.com/2023/day/5/input";
 
             let mut mock = HttpProviderMock::new();
             mock.insert_response(
-                expected_endpoint.to_string(),
+                ENDPOINT.to_string(),
                 "Mocked response data".to_string(),
             );
-            mock.assert_called_with(expected_endpoint.to_string());
+            mock.assert_called_with(ENDPOINT.to_string());
 
-            let service = HTTPService::new(mock);
-            let result = service.download_input(&puzzle);
+            let service = HTTPService::new(mock, FSMock::new());
+            let result =
+                service.download_input(&puzzle, Path::new("inputs"), false);
 
             assert!(result.is_ok());
             assert_eq!(result.unwrap(), "Mocked response data");
+            assert_eq!(
+                service.fs_provider.file(&cache()).unwrap(),
+                "Mocked response data"
+            );
+        }
+
+        #[test]
+        fn cached_input_is_not_fetched() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), "Cached data");
+
+            // The mock has no response: fetching would panic
+            let service = HTTPService::new(HttpProviderMock::new(), fs);
+            let result =
+                service.download_input(&puzzle, Path::new("inputs"), false);
+
+            assert_eq!(result.unwrap(), "Cached data");
+        }
+
+        #[test]
+        fn missing_cached_input() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+
+            let service =
+                HTTPService::new(HttpProviderMock::new(), FSMock::new());
+            let result = service.cached_input(&puzzle, Path::new("inputs"));
+
+            assert_eq!(result.unwrap(), None);
+        }
+
+        #[test]
+        fn force_refetches_cached_input() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), "Cached data");
+
+            let mut mock = HttpProviderMock::new();
+            mock.insert_response(
+                ENDPOINT.to_string(),
+                "Fresh data".to_string(),
+            );
+            mock.assert_called_with(ENDPOINT.to_string());
+
+            let service = HTTPService::new(mock, fs);
+            let result =
+                service.download_input(&puzzle, Path::new("inputs"), true);
+
+            assert_eq!(result.unwrap(), "Fresh data");
+            assert_eq!(
+                service.fs_provider.file(&cache()).unwrap(),
+                "Fresh data"
+            );
         }
 
         #[test]
         fn error_propagation() {
             let puzzle = Puzzle::new(2023, 5).unwrap();
-            let endpoint = "https://adventofcode.com/2023/day/5/input";
 
             let mut mock = HttpProviderMock::new();
             mock.insert_error(
-                endpoint.to_string(),
+                ENDPOINT.to_string(),
                 HTTPError::FetchError("Mocked fetch error".to_string()),
             );
 
-            let service = HTTPService::new(mock);
-            let result = service.download_input(&puzzle);
+            let service = HTTPService::new(mock, FSMock::new());
+            let result =
+                service.download_input(&puzzle, Path::new("inputs"), false);
 
             assert!(result.is_err());
             assert_eq!(
                 result.unwrap_err(),
                 HTTPError::FetchError("Mocked fetch error".to_string())
             );
+            assert!(!service.fs_provider.exists(&cache()));
+        }
+    }
+
+    mod download_description {
+        use super::super::*;
+        use super::*;
+        use crate::Puzzle;
+
+        const ENDPOINT: &str = "https://adventofcode.com/2023/day/5";
+        const PART_ONE: &str =
+            r#"<article class="day-desc"><h2>--- Day 5 ---</h2></article>"#;
+        const PART_TWO: &str =
+            r#"<article class="day-desc"><h2>--- Part Two ---</h2></article>"#;
+
+        fn cache() -> PathBuf {
+            ["inputs", "y2023", "day05.html"].iter().collect()
+        }
+
+        fn service(
+            page: Option<&str>,
+            fs: FSMock,
+        ) -> HTTPService<HttpProviderMock, FSMock> {
+            let mut mock = HttpProviderMock::new();
+            if let Some(page) = page {
+                mock.insert_response(ENDPOINT.to_string(), page.to_string());
+                mock.assert_called_with(ENDPOINT.to_string());
+            }
+
+            HTTPService::new(mock, fs)
+        }
+
+        #[test]
+        fn test_description_is_cached() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let page = format!("<main>{}<p>Answer: 1</p></main>", PART_ONE);
+
+            let service = service(Some(&page), FSMock::new());
+            let description = service
+                .download_description(&puzzle, None, Path::new("inputs"), false)
+                .unwrap();
+
+            assert!(description.has(Part::P1));
+            assert_eq!(service.fs_provider.file(&cache()).unwrap(), PART_ONE);
+        }
+
+        #[test]
+        fn test_cached_description_is_not_fetched() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), PART_ONE);
+
+            // The mock has no response: fetching would panic
+            let description = service(None, fs)
+                .download_description(
+                    &puzzle,
+                    Some(Part::P1),
+                    Path::new("inputs"),
+                    false,
+                )
+                .unwrap();
+
+            assert!(description.has(Part::P1));
+        }
+
+        #[test]
+        fn test_cached_description_without_part() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), PART_ONE);
+
+            let service = service(None, fs);
+            let inputs = Path::new("inputs");
+
+            assert!(service
+                .cached_description(&puzzle, None, inputs)
+                .unwrap()
+                .is_some());
+            assert!(service
+                .cached_description(&puzzle, Some(Part::P2), inputs)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn test_missing_part_is_fetched() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), PART_ONE);
+            let page = format!("{}{}", PART_ONE, PART_TWO);
+
+            let service = service(Some(&page), fs);
+            let description = service
+                .download_description(
+                    &puzzle,
+                    Some(Part::P2),
+                    Path::new("inputs"),
+                    false,
+                )
+                .unwrap();
+
+            assert!(description.has(Part::P2));
+            assert_eq!(
+                service.fs_provider.file(&cache()).unwrap(),
+                format!("{}\n{}", PART_ONE, PART_TWO)
+            );
+        }
+
+        #[test]
+        fn test_page_without_description() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+
+            let service = service(Some("<html></html>"), FSMock::new());
+            let result = service.download_description(
+                &puzzle,
+                None,
+                Path::new("inputs"),
+                false,
+            );
+
+            assert!(matches!(result, Err(HTTPError::ParseError(_))));
+            assert!(!service.fs_provider.exists(&cache()));
+        }
+    }
+
+    mod download_leaderboard {
+        use super::super::*;
+        use super::*;
+
+        const ENDPOINT: &str =
+            "https://adventofcode.com/2024/leaderboard/private/view/42.json";
+        const FIXTURE: &str =
+            include_str!("../../tests/test_data/leaderboard.json");
+
+        fn cache() -> PathBuf {
+            ["inputs", "y2024", "leaderboard_42.json"].iter().collect()
+        }
+
+        fn service(
+            response: Option<&str>,
+            fs: FSMock,
+        ) -> HTTPService<HttpProviderMock, FSMock> {
+            let mut mock = HttpProviderMock::new();
+            if let Some(response) = response {
+                mock.insert_response(
+                    ENDPOINT.to_string(),
+                    response.to_string(),
+                );
+                mock.assert_called_with(ENDPOINT.to_string());
+            }
+
+            HTTPService::new(mock, fs)
+        }
+
+        #[test]
+        fn test_leaderboard_is_cached() {
+            let service = service(Some(FIXTURE), FSMock::new());
+            let leaderboard = service
+                .download_leaderboard(2024, 42, Path::new("inputs"), false)
+                .unwrap();
+
+            assert_eq!(leaderboard.event, "2024");
+            assert_eq!(service.fs_provider.file(&cache()).unwrap(), FIXTURE);
+        }
+
+        #[test]
+        fn test_recent_cache_is_not_fetched() {
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), FIXTURE);
+
+            // The mock has no response: fetching would panic
+            let leaderboard = service(None, fs)
+                .download_leaderboard(2024, 42, Path::new("inputs"), false)
+                .unwrap();
+
+            assert_eq!(leaderboard.rankings().len(), 4);
+        }
+
+        #[test]
+        fn test_old_cache_is_fetched() {
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), "{}");
+            fs.set_modified(
+                &cache(),
+                SystemTime::now() - LEADERBOARD_CACHE_TTL,
+            );
+
+            let leaderboard = service(Some(FIXTURE), fs)
+                .download_leaderboard(2024, 42, Path::new("inputs"), false)
+                .unwrap();
+
+            assert_eq!(leaderboard.rankings().len(), 4);
+        }
+
+        #[test]
+        fn test_force_fetches_recent_cache() {
+            let mut fs = FSMock::new();
+            fs.insert_file(&cache(), "{}");
+
+            let service = service(Some(FIXTURE), fs);
+            service
+                .download_leaderboard(2024, 42, Path::new("inputs"), true)
+                .unwrap();
+
+            assert_eq!(service.fs_provider.file(&cache()).unwrap(), FIXTURE);
+        }
+
+        #[test]
+        fn test_not_a_member() {
+            let service = service(Some("<html></html>"), FSMock::new());
+            let result = service.download_leaderboard(
+                2024,
+                42,
+                Path::new("inputs"),
+                false,
+            );
+
+            assert!(matches!(result, Err(HTTPError::ParseError(_))));
+            assert!(!service.fs_provider.exists(&cache()));
+        }
+    }
+
+    mod submit_answer {
+        use super::super::*;
+        use super::*;
+        use crate::{Part, Puzzle};
+
+        const ENDPOINT: &str = "https://adventofcode.com/2023/day/5/answer";
+
+        fn service_answering(
+            response: &str,
+        ) -> HTTPService<HttpProviderMock, FSMock> {
+            let mut mock = HttpProviderMock::new();
+            mock.insert_response(ENDPOINT.to_string(), response.to_string());
+            mock.assert_called_with(ENDPOINT.to_string());
+
+            HTTPService::new(mock, FSMock::new())
+        }
+
+        fn submit(response: &str) -> Result<SubmitOutcome, HTTPError> {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+
+            service_answering(response).submit_answer(&puzzle, Part::P2, "1234")
+        }
+
+        #[test]
+        fn test_form_fields() {
+            let puzzle = Puzzle::new(2023, 5).unwrap();
+            let service = service_answering(
+                "<article><p>That's the right answer!</p></article>",
+            );
+            let _ = service.submit_answer(&puzzle, Part::P2, "1234");
+
+            assert_eq!(
+                service.http_provider.posted_forms(),
+                vec![vec![
+                    ("level".to_string(), "2".to_string()),
+                    ("answer".to_string(), "1234".to_string())
+                ]]
+            );
+        }
+
+        #[test]
+        fn test_right_answer() {
+            let result = submit("<article><p>That's the right answer!  You are one gold star closer.</p></article>");
+
+            assert_eq!(result, Ok(SubmitOutcome::Right));
+        }
+
+        #[test]
+        fn test_wrong_answer() {
+            let result = submit("<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>");
+
+            assert_eq!(result, Ok(SubmitOutcome::Wrong));
+        }
+
+        #[test]
+        fn test_too_high() {
+            let result = submit("<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>");
+
+            assert_eq!(result, Ok(SubmitOutcome::TooHigh));
+        }
+
+        #[test]
+        fn test_too_low() {
+            let result = submit("<article><p>That's not the right answer; your answer is too low.</p></article>");
+
+            assert_eq!(result, Ok(SubmitOutcome::TooLow));
+        }
+
+        #[test]
+        fn test_rate_limited() {
+            let result = submit("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>");
+
+            assert_eq!(
+                result,
+                Ok(SubmitOutcome::RateLimited(Duration::from_secs(65)))
+            );
+        }
+
+        #[test]
+        fn test_wrong_level() {
+            let result = submit("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>");
+
+            assert_eq!(result, Ok(SubmitOutcome::WrongLevel));
+        }
+
+        #[test]
+        fn test_unexpected_response() {
+            let result = submit("<html></html>");
+
+            assert!(matches!(result, Err(HTTPError::ParseError(_))));
+        }
+    }
+
+    mod check_session {
+        use super::super::*;
+        use super::*;
+
+        const ENDPOINT: &str = "https://adventofcode.com/settings";
+
+        fn check(response: &str) -> Result<String, HTTPError> {
+            let mut mock = HttpProviderMock::new();
+            mock.insert_response(ENDPOINT.to_string(), response.to_string());
+            mock.assert_called_with(ENDPOINT.to_string());
+
+            HTTPService::new(mock, FSMock::new()).check_session()
+        }
+
+        #[test]
+        fn test_logged_in_user() {
+            let result = check(
+                r#"<html><body><header><div><div class="user">Alice <span class="star-count">42*</span></div></div></header></body></html>"#,
+            );
+
+            assert_eq!(result, Ok("Alice".to_string()));
+        }
+
+        #[test]
+        fn test_anonymous_user() {
+            let result = check(
+                r#"<header><div class="user">(anonymous user #12345)</div></header>"#,
+            );
+
+            assert_eq!(result, Ok("(anonymous user #12345)".to_string()));
+        }
+
+        #[test]
+        fn test_logged_out() {
+            let result = check(
+                r#"<header><a href="/2024/auth/login">[Log In]</a></header>"#,
+            );
+
+            assert_eq!(result, Err(HTTPError::ExpiredCookie));
         }
     }
 
@@ -129,8 +1030,25 @@ mod tests {
 
             mock.assert_cookie_value(cookie.to_owned());
 
-            let mut service = HTTPService::new(mock);
+            let mut service = HTTPService::new(mock, FSMock::new());
             service.set_cookie(cookie.to_string());
         }
     }
+
+    mod set_user_agent {
+        use super::super::*;
+        use super::*;
+
+        #[test]
+        fn test_user_agent_is_set() {
+            let mut service =
+                HTTPService::new(HttpProviderMock::new(), FSMock::new());
+            service.set_user_agent("aoc-test".to_string());
+
+            assert_eq!(
+                service.http_provider.user_agent(),
+                Some("aoc-test".to_string())
+            );
+        }
+    }
 }
@@ -1,29 +1,208 @@
 //! This contains the solutions to the different challenges
 
-use crate::Execute;
+use crate::{Execute, Puzzle};
 use derive_more::Display;
+use exitcode::ExitCode;
+use log::debug;
+use std::any::Any;
+use std::cell::Cell;
+use std::fs::File;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
 
+mod bench;
+mod executor;
 pub mod y2023;
 pub mod y2024;
 
+pub use bench::{Bench, BenchReport, Stats};
+pub use executor::{Executor, Job};
+
+/// Signature shared by the `solve` function of every implemented day
+pub type SolveFn = fn(Execute, &str) -> Report;
+
+/// Every year with implemented solvers, together with its registered days.
+///
+/// `aoc generate` adds new years here automatically.
+#[rustfmt::skip]
+const REGISTRY: &[(u32, &[(u32, SolveFn)])] = &[
+    (2023, y2023::SOLVERS),
+    (2024, y2024::SOLVERS),
+];
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Display, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    /// Integers that do not fit in 64 bits
+    Big(i128),
+    /// Strings, such as comma-separated values or letters read off a grid
+    Text(String),
+}
+
+impl Answer {
+    /// Returns whether the answer is a number
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Answer::Text(_))
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Errors raised while solving a puzzle
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum SolverError {
+    #[error("I/O error: {0}")]
+    Io(String),
+
+    /// The input does not follow the expected format. `line` and `column`
+    /// are 1-based, and `context` holds the offending line.
+    #[error("parse error at line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        context: String,
+        message: String,
+    },
+
+    /// The input is well-formed, but the solver cannot work with it
+    #[error("{0}")]
+    InvalidInput(String),
+
+    #[error("timed out after {0:?}")]
+    Timeout(Duration),
+
+    #[error("solver panicked: {0}")]
+    Panic(String),
+}
+
+impl SolverError {
+    /// Creates a parse error at the given 1-based `line` and `column`,
+    /// where `context` is the text of that line
+    pub fn parse(
+        line: usize,
+        column: usize,
+        context: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        SolverError::Parse {
+            line,
+            column,
+            context: context.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a parse error pointing at `field`, which must be a slice of
+    /// `context`, the text of the 1-based `line`
+    pub fn parse_field(
+        line: usize,
+        context: &str,
+        field: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        let offset = (field.as_ptr() as usize)
+            .checked_sub(context.as_ptr() as usize)
+            .filter(|offset| *offset <= context.len())
+            .unwrap_or(0);
+
+        Self::parse(line, offset + 1, context, message)
+    }
+
+    /// Creates a parse error at the given byte `offset` of the whole `input`
+    pub fn parse_at_offset(
+        input: &str,
+        offset: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line = input[..offset].matches('\n').count() + 1;
+        let context = input[line_start..].lines().next().unwrap_or_default();
+
+        Self::parse(line, offset - line_start + 1, context, message)
+    }
+
+    /// Exit code that the binary uses to report this kind of error. Inputs
+    /// that were read but cannot be used are all data errors.
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            SolverError::Io(_) => exitcode::IOERR,
+            SolverError::Parse { .. } | SolverError::InvalidInput(_) => {
+                exitcode::DATAERR
+            }
+            SolverError::Timeout(_) => exitcode::TEMPFAIL,
+            SolverError::Panic(_) => exitcode::SOFTWARE,
+        }
+    }
+}
+
+impl From<std::io::Error> for SolverError {
+    fn from(value: std::io::Error) -> Self {
+        SolverError::Io(value.to_string())
+    }
+}
+
 /// Solution returned by the solver
 #[derive(Debug, Display, PartialEq, Eq, Clone)]
 pub enum Solution {
-    Value(u64),
-    Err(String),
+    Value(Answer),
+    Err(SolverError),
     NotExecuted,
     NotImplemented,
+    /// Part 2 of a puzzle that only has one part, see
+    /// [`Puzzle::is_single_part`]
+    SinglePart,
 }
 
 #[derive(Debug, Display, PartialEq, Eq, Clone)]
 pub enum SolutionExecution {
-    Value(u64),
+    Value(Answer),
     NotImplemented,
 }
 
-impl From<Result<SolutionExecution, String>> for Solution {
-    fn from(value: Result<SolutionExecution, String>) -> Self {
+impl From<Result<SolutionExecution, SolverError>> for Solution {
+    fn from(value: Result<SolutionExecution, SolverError>) -> Self {
         match value {
             Err(s) => Solution::Err(s),
             Ok(execution_value) => match execution_value {
@@ -34,24 +213,594 @@ impl From<Result<SolutionExecution, String>> for Solution {
     }
 }
 
-/// Runs the required solvers and returns a [Solution; 2] that can be used
-/// to interpret the results
-fn common_solve(
+/// A solver for one puzzle.
+///
+/// The puzzle input is parsed once into [`Solver::Input`], which is then
+/// shared by both parts.
+pub trait Solver {
+    /// Parsed representation of the puzzle input
+    type Input;
+
+    /// Parses the raw puzzle input
+    fn parse(input: &str) -> Result<Self::Input, SolverError>;
+
+    /// Solves the first part of the puzzle
+    fn part1(input: &Self::Input) -> Result<SolutionExecution, SolverError>;
+
+    /// Solves the second part of the puzzle
+    fn part2(input: &Self::Input) -> Result<SolutionExecution, SolverError>;
+}
+
+/// Signature of [`Solver::part1`] and [`Solver::part2`]
+type PartFn<I> = fn(&I) -> Result<SolutionExecution, SolverError>;
+
+/// Time spent on each step of a solver run. Steps that did not run are
+/// `None`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub parts: [Option<Duration>; 2],
+}
+
+impl Timings {
+    /// Returns the time spent on all the steps that ran
+    pub fn total(&self) -> Duration {
+        self.parts.iter().chain([&self.parse]).flatten().sum()
+    }
+}
+
+/// Outcome of a solver run: the solutions and how long each step took
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report {
+    pub solutions: [Solution; 2],
+    pub timings: Timings,
+}
+
+impl Report {
+    /// Creates a report where every requested part failed with `err`
+    fn failed(execute: Execute, err: SolverError) -> Self {
+        let solutions = execute.parts().map(|requested| match requested {
+            true => Solution::Err(err.clone()),
+            false => Solution::NotExecuted,
+        });
+
+        Self {
+            solutions,
+            timings: Timings::default(),
+        }
+    }
+
+    /// Combines the reports of two runs of the same puzzle. Each part is
+    /// taken from the run that executed it, and the parse time is the
+    /// slowest of both.
+    fn merge(self, other: Report) -> Self {
+        let [p1, p2] = self.solutions;
+        let [other_p1, other_p2] = other.solutions;
+        let pick = |solution: Solution, other: Solution| match solution {
+            Solution::NotExecuted => other,
+            _ => solution,
+        };
+
+        Self {
+            solutions: [pick(p1, other_p1), pick(p2, other_p2)],
+            timings: Timings {
+                parse: self.timings.parse.max(other.timings.parse),
+                parts: [
+                    self.timings.parts[0].or(other.timings.parts[0]),
+                    self.timings.parts[1].or(other.timings.parts[1]),
+                ],
+            },
+        }
+    }
+
+    /// Reports the part 2 of a single-part puzzle as
+    /// [`Solution::SinglePart`], whatever its solver returned
+    pub fn for_puzzle(mut self, puzzle: &Puzzle) -> Self {
+        if mark_single_part(&mut self.solutions, puzzle) {
+            self.timings.parts[1] = None;
+        }
+
+        self
+    }
+
+    /// Returns the first error among the solutions, if any
+    pub fn first_error(&self) -> Option<&SolverError> {
+        first_error(&self.solutions)
+    }
+}
+
+/// Replaces the executed part 2 of a single-part puzzle with
+/// [`Solution::SinglePart`]. Returns whether it was replaced, so that its
+/// timing can be dropped too.
+fn mark_single_part(solutions: &mut [Solution; 2], puzzle: &Puzzle) -> bool {
+    if !puzzle.is_single_part() || solutions[1] == Solution::NotExecuted {
+        return false;
+    }
+
+    solutions[1] = Solution::SinglePart;
+
+    true
+}
+
+/// Returns the first error among the solutions, if any
+fn first_error(solutions: &[Solution; 2]) -> Option<&SolverError> {
+    solutions.iter().find_map(|solution| match solution {
+        Solution::Err(err) => Some(err),
+        _ => None,
+    })
+}
+
+/// Returns the solver registered for the given puzzle, if any
+pub fn get_solver(puzzle: &Puzzle) -> Option<SolveFn> {
+    REGISTRY
+        .iter()
+        .find(|(year, _)| *year == puzzle.year())
+        .and_then(|(_, days)| days.iter().find(|(day, _)| *day == puzzle.day()))
+        .map(|(_, solver)| *solver)
+}
+
+/// Returns the years that have at least one registered solver, in
+/// ascending order
+pub fn implemented_years() -> Vec<u32> {
+    let mut years: Vec<u32> = REGISTRY.iter().map(|(year, _)| *year).collect();
+    years.sort();
+
+    years
+}
+
+/// Returns every puzzle with a registered solver, sorted by year and day
+pub fn implemented_puzzles() -> Vec<Puzzle> {
+    let mut puzzles: Vec<Puzzle> = REGISTRY
+        .iter()
+        .flat_map(|(year, days)| {
+            days.iter()
+                .filter_map(|(day, _)| Puzzle::new(*year, *day).ok())
+        })
+        .collect();
+    puzzles.sort_by_key(|puzzle| (puzzle.year(), puzzle.day()));
+
+    puzzles
+}
+
+/// Runs the solver with the input read from `reader`. When `timeout` is
+/// set, the run is abandoned once it takes longer than that.
+pub fn solve_reader(
+    solver: SolveFn,
+    execute: Execute,
+    mut reader: impl Read,
+    timeout: Option<Duration>,
+) -> Report {
+    let mut input = String::new();
+
+    match reader.read_to_string(&mut input) {
+        Ok(_) => run(solver, execute, input, timeout),
+        Err(e) => Report::failed(execute, e.into()),
+    }
+}
+
+/// Runs the solver with the input stored in the file at `input_path`.
+/// When `timeout` is set, the run is abandoned once it takes longer than
+/// that.
+pub fn solve_file(
+    solver: SolveFn,
     execute: Execute,
     input_path: &Path,
-    solve_part1: fn(&Path) -> Result<SolutionExecution, String>,
-    solve_part2: fn(&Path) -> Result<SolutionExecution, String>,
-) -> [Solution; 2] {
-    let mut solutions: [Solution; 2] =
-        [Solution::NotExecuted, Solution::NotExecuted];
+    timeout: Option<Duration>,
+) -> Report {
+    match File::open(input_path) {
+        Ok(file) => solve_reader(solver, execute, file, timeout),
+        Err(e) => Report::failed(
+            execute,
+            SolverError::Io(format!("{}: {}", input_path.display(), e)),
+        ),
+    }
+}
+
+/// Runs the solver, in a separate thread if there is a timeout to enforce.
+///
+/// A solver that times out cannot be stopped: its thread is left behind,
+/// and its result discarded.
+fn run(
+    solver: SolveFn,
+    execute: Execute,
+    input: String,
+    timeout: Option<Duration>,
+) -> Report {
+    let Some(timeout) = timeout else {
+        return solver(execute, &input);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone when the run timed out, nobody is waiting
+        let _ = sender.send(solver(execute, &input));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(report) => report,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            Report::failed(execute, SolverError::Timeout(timeout))
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => Report::failed(
+            execute,
+            SolverError::Panic("the solver stopped unexpectedly".into()),
+        ),
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is running solver code
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Keeps the panics of the solvers away from the panic hook, as they are
+/// already reported as [`SolverError::Panic`]. Any other panic still goes
+/// to the hook installed before.
+///
+/// The panic hook is global to the process, so this is left to the binary:
+/// call it once from `main`, after installing its own hook.
+pub fn quiet_solver_panics() {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| match SOLVING.with(Cell::get) {
+        true => debug!("Solver panicked: {}", info),
+        false => previous_hook(info),
+    }));
+}
+
+/// Runs `f`, turning any panic into a [`SolverError::Panic`]
+fn catch_panic<T>(
+    f: impl FnOnce() -> Result<T, SolverError>,
+) -> Result<T, SolverError> {
+    SOLVING.with(|solving| solving.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SOLVING.with(|solving| solving.set(false));
+
+    result.unwrap_or_else(|payload| Err(SolverError::Panic(message(payload))))
+}
+
+/// Extracts the message of a panic payload
+fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+/// Parses the input once, then runs the required parts of the solver.
+/// Errors and panics from any step are mapped into the returned
+/// [`Solution`]s.
+fn common_solve<S: Solver>(execute: Execute, input: &str) -> Report {
+    let start = Instant::now();
+    let parsed_input = catch_panic(|| S::parse(input));
+    let parse_time = start.elapsed();
+    debug!("Input parsed in {:?}", parse_time);
+
+    let parsed_input = match parsed_input {
+        Ok(parsed_input) => parsed_input,
+        Err(err) => {
+            let mut report = Report::failed(execute, err);
+            report.timings.parse = Some(parse_time);
+
+            return report;
+        }
+    };
+
+    let mut report = Report {
+        solutions: [Solution::NotExecuted, Solution::NotExecuted],
+        timings: Timings {
+            parse: Some(parse_time),
+            parts: [None, None],
+        },
+    };
+    let parts: [PartFn<S::Input>; 2] = [S::part1, S::part2];
+
+    for (index, requested) in execute.parts().into_iter().enumerate() {
+        if !requested {
+            continue;
+        }
+
+        let start = Instant::now();
+        report.solutions[index] =
+            catch_panic(|| parts[index](&parsed_input)).into();
+        report.timings.parts[index] = Some(start.elapsed());
+        debug!(
+            "Part {} solved in {:?}",
+            index + 1,
+            report.timings.parts[index].unwrap()
+        );
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Parses a single number, doubles it on part 1 and fails on part 2
+    struct Doubler;
+
+    impl Solver for Doubler {
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, SolverError> {
+            let line = input.lines().next().unwrap_or_default();
+
+            line.trim()
+                .parse()
+                .map_err(|_| SolverError::parse(1, 1, line, "NaN"))
+        }
+
+        fn part1(
+            input: &Self::Input,
+        ) -> Result<SolutionExecution, SolverError> {
+            Ok(SolutionExecution::Value((input * 2).into()))
+        }
+
+        fn part2(_: &Self::Input) -> Result<SolutionExecution, SolverError> {
+            Err(SolverError::InvalidInput("ERR".to_string()))
+        }
+    }
+
+    /// Panics on part 1, and takes its time on part 2
+    struct Misbehaving;
+
+    impl Solver for Misbehaving {
+        type Input = ();
+
+        fn parse(_: &str) -> Result<Self::Input, SolverError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> Result<SolutionExecution, SolverError> {
+            panic!("BOOM");
+        }
+
+        fn part2(_: &Self::Input) -> Result<SolutionExecution, SolverError> {
+            thread::sleep(Duration::from_secs(1));
+
+            Ok(SolutionExecution::NotImplemented)
+        }
+    }
+
+    fn solve_doubler(execute: Execute, input: &str) -> Report {
+        common_solve::<Doubler>(execute, input)
+    }
+
+    fn solve_misbehaving(execute: Execute, input: &str) -> Report {
+        common_solve::<Misbehaving>(execute, input)
+    }
+
+    mod solver_error {
+        use super::*;
+
+        #[test]
+        fn test_parse_field() {
+            let line = "3   x4";
+            let err = SolverError::parse_field(7, line, &line[4..], "NaN");
+
+            assert_eq!(err, SolverError::parse(7, 5, line, "NaN"));
+        }
+
+        #[test]
+        fn test_parse_at_offset() {
+            let input = "123\n45x6\n789";
+            let err = SolverError::parse_at_offset(input, 6, "Unexpected x");
+
+            assert_eq!(err, SolverError::parse(2, 3, "45x6", "Unexpected x"));
+        }
+
+        #[test]
+        fn test_invalid_input_is_a_data_error() {
+            assert_eq!(
+                SolverError::InvalidInput(String::new()).exit_code(),
+                exitcode::DATAERR
+            );
+            assert_eq!(
+                SolverError::parse(1, 1, "", "").exit_code(),
+                exitcode::DATAERR
+            );
+        }
+
+        #[test]
+        fn test_exit_codes_are_distinct() {
+            let errors = [
+                SolverError::Io(String::new()),
+                SolverError::parse(1, 1, "", ""),
+                SolverError::Timeout(Duration::ZERO),
+                SolverError::Panic(String::new()),
+            ];
+            let mut codes: Vec<ExitCode> =
+                errors.iter().map(SolverError::exit_code).collect();
+            codes.sort();
+            codes.dedup();
 
-    if execute == Execute::ALL || execute == Execute::P1 {
-        solutions[0] = solve_part1(&input_path).into();
+            assert_eq!(codes.len(), errors.len());
+        }
     }
 
-    if execute == Execute::ALL || execute == Execute::P2 {
-        solutions[1] = solve_part2(&input_path).into();
+    mod common_solve {
+        use super::*;
+
+        #[test]
+        fn test_all_parts() {
+            let report = common_solve::<Doubler>(Execute::ALL, "21");
+
+            assert_eq!(
+                report.solutions,
+                [
+                    Solution::Value(Answer::Unsigned(42)),
+                    Solution::Err(SolverError::InvalidInput("ERR".to_string()))
+                ]
+            );
+            assert!(report.timings.parse.is_some());
+            assert!(report.timings.parts.iter().all(Option::is_some));
+        }
+
+        #[test]
+        fn test_single_part() {
+            let report = common_solve::<Doubler>(Execute::P1, "21");
+
+            assert_eq!(
+                report.solutions,
+                [Solution::Value(Answer::Unsigned(42)), Solution::NotExecuted]
+            );
+            assert_eq!(report.timings.parts[1], None);
+        }
+
+        #[test]
+        fn test_parse_error_reaches_requested_parts() {
+            let report = common_solve::<Doubler>(Execute::P2, "twenty one");
+
+            assert_eq!(
+                report.solutions,
+                [
+                    Solution::NotExecuted,
+                    Solution::Err(SolverError::parse(
+                        1,
+                        1,
+                        "twenty one",
+                        "NaN"
+                    ))
+                ]
+            );
+            assert_eq!(report.timings.parts, [None, None]);
+        }
+
+        #[test]
+        fn test_panics_are_caught() {
+            let report = common_solve::<Misbehaving>(Execute::P1, "");
+
+            assert_eq!(
+                report.solutions[0],
+                Solution::Err(SolverError::Panic("BOOM".to_string()))
+            );
+        }
     }
 
-    solutions
+    mod input_sources {
+        use super::*;
+
+        #[test]
+        fn test_solve_reader() {
+            let reader = Cursor::new("21\n");
+            let report = solve_reader(solve_doubler, Execute::P1, reader, None);
+
+            assert_eq!(
+                report.solutions,
+                [Solution::Value(Answer::Unsigned(42)), Solution::NotExecuted]
+            );
+        }
+
+        #[test]
+        fn test_missing_file() {
+            let path = Path::new("this/file/does/not/exist.txt");
+            let report = solve_file(solve_doubler, Execute::ALL, path, None);
+
+            assert!(matches!(report.first_error(), Some(SolverError::Io(_))));
+            assert_eq!(report.solutions[0], report.solutions[1]);
+        }
+
+        #[test]
+        fn test_timeout() {
+            let reader = Cursor::new("");
+            let timeout = Duration::from_millis(10);
+            let report = solve_reader(
+                solve_misbehaving,
+                Execute::P2,
+                reader,
+                Some(timeout),
+            );
+
+            assert_eq!(
+                report.solutions,
+                [
+                    Solution::NotExecuted,
+                    Solution::Err(SolverError::Timeout(timeout))
+                ]
+            );
+        }
+    }
+
+    mod for_puzzle {
+        use super::*;
+
+        #[test]
+        fn test_single_part_puzzle() {
+            let puzzle = Puzzle::new(2024, 25).unwrap();
+            let report =
+                common_solve::<Doubler>(Execute::P2, "21").for_puzzle(&puzzle);
+
+            assert_eq!(
+                report.solutions,
+                [Solution::NotExecuted, Solution::SinglePart]
+            );
+            assert_eq!(report.timings.parts[1], None);
+        }
+
+        #[test]
+        fn test_part_1_of_single_part_puzzle() {
+            let puzzle = Puzzle::new(2025, 12).unwrap();
+            let report =
+                common_solve::<Doubler>(Execute::P1, "21").for_puzzle(&puzzle);
+
+            assert_eq!(
+                report.solutions,
+                [Solution::Value(Answer::Unsigned(42)), Solution::NotExecuted]
+            );
+        }
+
+        #[test]
+        fn test_two_parts_puzzle() {
+            let puzzle = Puzzle::new(2024, 24).unwrap();
+            let report = common_solve::<Doubler>(Execute::ALL, "21");
+
+            assert_eq!(report.clone().for_puzzle(&puzzle), report);
+        }
+    }
+
+    #[test]
+    fn test_timings_total_skips_missing_steps() {
+        let timings = Timings {
+            parse: Some(Duration::from_millis(3)),
+            parts: [None, Some(Duration::from_millis(4))],
+        };
+
+        assert_eq!(timings.total(), Duration::from_millis(7));
+    }
+
+    #[test]
+    fn test_get_solver_for_registered_puzzle() {
+        let puzzle = Puzzle::new(2023, 1).unwrap();
+
+        assert!(get_solver(&puzzle).is_some());
+    }
+
+    #[test]
+    fn test_get_solver_for_missing_puzzle() {
+        let puzzle = Puzzle::new(2024, 25).unwrap();
+
+        assert!(get_solver(&puzzle).is_none());
+    }
+
+    #[test]
+    fn test_implemented_puzzles_are_sorted() {
+        let puzzles = implemented_puzzles();
+        let mut sorted = puzzles.clone();
+        sorted.sort_by_key(|puzzle| (puzzle.year(), puzzle.day()));
+
+        assert!(!puzzles.is_empty());
+        assert_eq!(puzzles, sorted);
+    }
+
+    #[test]
+    fn test_implemented_puzzles_have_solvers() {
+        for puzzle in implemented_puzzles() {
+            assert!(get_solver(&puzzle).is_some());
+        }
+    }
 }
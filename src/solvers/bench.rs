@@ -0,0 +1,350 @@
+//! Times solvers over many runs, without relying on the nightly `#[bench]`
+
+use super::{
+    first_error, mark_single_part, Report, Solution, SolveFn, SolverError,
+};
+use crate::{Execute, Puzzle};
+use log::trace;
+use std::fs;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::time::Duration;
+
+/// Summary of the time a step took over all the measured runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, zero for a single run
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes the given samples. Returns `None` when there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let min = *sorted.first()?;
+        let median = match count % 2 {
+            0 => (sorted[count / 2 - 1] + sorted[count / 2]) / 2,
+            _ => sorted[count / 2],
+        };
+
+        let nanos: Vec<f64> = sorted
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = match count {
+            1 => 0.0,
+            _ => {
+                nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+                    / (count - 1) as f64
+            }
+        };
+
+        Some(Self {
+            min,
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Outcome of a benchmark: the solutions of the first measured run, and the
+/// timing statistics of each step. Steps without a solution are not timed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchReport {
+    pub solutions: [Solution; 2],
+    /// Number of measured runs, warm-up excluded
+    pub runs: usize,
+    pub parse: Option<Stats>,
+    pub parts: [Option<Stats>; 2],
+}
+
+impl BenchReport {
+    /// Creates a report with the given solutions, and nothing timed
+    fn untimed(solutions: [Solution; 2]) -> Self {
+        Self {
+            solutions,
+            runs: 0,
+            parse: None,
+            parts: [None, None],
+        }
+    }
+
+    /// Reports the part 2 of a single-part puzzle as
+    /// [`Solution::SinglePart`], see [`Report::for_puzzle`]
+    pub fn for_puzzle(mut self, puzzle: &Puzzle) -> Self {
+        if mark_single_part(&mut self.solutions, puzzle) {
+            self.parts[1] = None;
+        }
+
+        self
+    }
+
+    /// Returns the first error among the solutions, if any
+    pub fn first_error(&self) -> Option<&SolverError> {
+        first_error(&self.solutions)
+    }
+}
+
+/// Runs a solver repeatedly on the same input: first a few warm-up runs
+/// that are not measured, then the measured ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    warmup: usize,
+    runs: NonZeroUsize,
+}
+
+impl Default for Bench {
+    /// Three warm-up runs, followed by ten measured runs
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: NonZeroUsize::new(10).expect("10 is not zero"),
+        }
+    }
+}
+
+impl Bench {
+    /// Sets the number of runs done before measuring
+    pub fn set_warmup(&mut self, warmup: usize) -> &mut Self {
+        self.warmup = warmup;
+
+        self
+    }
+
+    /// Sets the number of measured runs
+    pub fn set_runs(&mut self, runs: NonZeroUsize) -> &mut Self {
+        self.runs = runs;
+
+        self
+    }
+
+    /// Benchmarks the requested parts of the solver with the given input.
+    ///
+    /// When a run fails, the benchmark stops right away and nothing is
+    /// timed.
+    pub fn run(
+        &self,
+        solver: SolveFn,
+        execute: Execute,
+        input: &str,
+    ) -> BenchReport {
+        trace!(
+            "Warming up with {} run(s), then measuring {} run(s)...",
+            self.warmup,
+            self.runs
+        );
+        let mut reports: Vec<Report> = Vec::with_capacity(self.runs.get());
+        for run in 0..self.warmup + self.runs.get() {
+            let report = solver(execute, input);
+            if report.first_error().is_some() {
+                return BenchReport::untimed(report.solutions);
+            }
+
+            // The warm-up runs are not measured
+            if run >= self.warmup {
+                reports.push(report);
+            }
+        }
+        let first = &reports[0];
+
+        let stats = |timing: fn(&Report) -> Option<Duration>| {
+            let samples: Vec<Duration> =
+                reports.iter().filter_map(timing).collect();
+
+            Stats::from_samples(&samples)
+        };
+        let solved =
+            |index: usize| matches!(first.solutions[index], Solution::Value(_));
+
+        BenchReport {
+            parse: stats(|report| report.timings.parse),
+            parts: [
+                stats(|report| report.timings.parts[0]).filter(|_| solved(0)),
+                stats(|report| report.timings.parts[1]).filter(|_| solved(1)),
+            ],
+            solutions: first.solutions.clone(),
+            runs: self.runs.get(),
+        }
+    }
+
+    /// Benchmarks the requested parts of the solver with the input stored
+    /// in the file at `input_path`
+    pub fn run_file(
+        &self,
+        solver: SolveFn,
+        execute: Execute,
+        input_path: &Path,
+    ) -> BenchReport {
+        match fs::read_to_string(input_path) {
+            Ok(input) => self.run(solver, execute, &input),
+            Err(e) => BenchReport::untimed(
+                Report::failed(
+                    execute,
+                    SolverError::Io(format!("{}: {}", input_path.display(), e)),
+                )
+                .solutions,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::{Answer, Timings};
+    use std::cell::Cell;
+
+    thread_local! {
+        /// Number of runs of the fake solvers on the current thread
+        static RUNS: Cell<u64> = const { Cell::new(0) };
+    }
+
+    /// Solves part 1 with the input, and takes one more microsecond on
+    /// every step of each run
+    fn solve_counting(execute: Execute, input: &str) -> Report {
+        let run = RUNS.with(|runs| runs.replace(runs.get() + 1)) + 1;
+        let elapsed = Duration::from_micros(run);
+        let solutions = execute.parts().map(|requested| match requested {
+            true => Solution::Value(Answer::Text(input.to_string())),
+            false => Solution::NotExecuted,
+        });
+
+        Report {
+            solutions: [solutions[0].clone(), Solution::NotImplemented],
+            timings: Timings {
+                parse: Some(elapsed),
+                parts: execute
+                    .parts()
+                    .map(|requested| requested.then_some(elapsed)),
+            },
+        }
+    }
+
+    fn solve_failing(execute: Execute, _: &str) -> Report {
+        RUNS.with(|runs| runs.set(runs.get() + 1));
+
+        Report::failed(execute, SolverError::InvalidInput("ERR".to_string()))
+    }
+
+    fn micros(micros: u64) -> Duration {
+        Duration::from_micros(micros)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats =
+            Stats::from_samples(&[micros(4), micros(1), micros(3), micros(2)])
+                .unwrap();
+
+        assert_eq!(stats.min, micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        // sqrt(5 / 3) µs
+        assert_eq!(stats.stddev, Duration::from_nanos(1291));
+    }
+
+    #[test]
+    fn test_stats_of_a_single_sample() {
+        let stats = Stats::from_samples(&[micros(7)]).unwrap();
+
+        assert_eq!(
+            stats,
+            Stats {
+                min: micros(7),
+                median: micros(7),
+                mean: micros(7),
+                stddev: Duration::ZERO,
+            }
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_warmup_runs_are_not_measured() {
+        RUNS.with(|runs| runs.set(0));
+        let report = Bench::default()
+            .set_warmup(2)
+            .set_runs(NonZeroUsize::new(3).unwrap())
+            .run(solve_counting, Execute::ALL, "42");
+
+        assert_eq!(RUNS.with(Cell::get), 5);
+        assert_eq!(report.runs, 3);
+        assert_eq!(
+            report.solutions,
+            [
+                Solution::Value(Answer::Text("42".to_string())),
+                Solution::NotImplemented
+            ]
+        );
+
+        let expected = Stats::from_samples(&[micros(3), micros(4), micros(5)]);
+        assert_eq!(report.parse, expected);
+        assert_eq!(report.parts, [expected, None]);
+    }
+
+    #[test]
+    fn test_without_warmup() {
+        RUNS.with(|runs| runs.set(0));
+        let report = Bench::default()
+            .set_warmup(0)
+            .set_runs(NonZeroUsize::new(2).unwrap())
+            .run(solve_counting, Execute::P1, "42");
+
+        assert_eq!(RUNS.with(Cell::get), 2);
+        assert_eq!(report.parse, Stats::from_samples(&[micros(1), micros(2)]));
+    }
+
+    #[test]
+    fn test_parts_not_requested_are_not_timed() {
+        let report = Bench::default().run(solve_counting, Execute::P2, "42");
+
+        assert_eq!(report.solutions[0], Solution::NotExecuted);
+        assert!(report.parse.is_some());
+        assert_eq!(report.parts, [None, None]);
+    }
+
+    #[test]
+    fn test_failure_stops_the_benchmark() {
+        RUNS.with(|runs| runs.set(0));
+        let report = Bench::default().run(solve_failing, Execute::ALL, "");
+
+        assert_eq!(RUNS.with(Cell::get), 1);
+        assert_eq!(report.runs, 0);
+        assert_eq!(
+            report.first_error(),
+            Some(&SolverError::InvalidInput("ERR".to_string()))
+        );
+        assert_eq!(report.parse, None);
+    }
+
+    #[test]
+    fn test_missing_input_file() {
+        let report = Bench::default().run_file(
+            solve_counting,
+            Execute::P1,
+            Path::new("missing.txt"),
+        );
+
+        assert!(matches!(report.first_error(), Some(SolverError::Io(_))));
+        assert_eq!(report.solutions[1], Solution::NotExecuted);
+    }
+
+    #[test]
+    fn test_single_part_puzzle() {
+        let puzzle = Puzzle::new(2024, 25).unwrap();
+        let report = Bench::default()
+            .run(solve_counting, Execute::ALL, "42")
+            .for_puzzle(&puzzle);
+
+        assert_eq!(report.solutions[1], Solution::SinglePart);
+        assert_eq!(report.parts[1], None);
+    }
+}
@@ -0,0 +1,244 @@
+//! Runs many puzzles at once on a bounded pool of worker threads
+
+use super::{solve_file, Report, SolveFn};
+use crate::Execute;
+use log::trace;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// A puzzle to solve: its solver and the file holding its input
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub solver: SolveFn,
+    pub input_path: PathBuf,
+}
+
+/// Solves batches of puzzles in parallel. The reports are always returned
+/// in the same order as the jobs, whatever the order they finished in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Executor {
+    workers: NonZeroUsize,
+    split_parts: bool,
+    timeout: Option<Duration>,
+}
+
+impl Default for Executor {
+    /// One worker per available core, with the parts of a puzzle solved
+    /// together and no timeout
+    fn default() -> Self {
+        Self {
+            workers: thread::available_parallelism()
+                .unwrap_or(NonZeroUsize::MIN),
+            split_parts: false,
+            timeout: None,
+        }
+    }
+}
+
+impl Executor {
+    /// Sets the maximum number of puzzles being solved at the same time
+    pub fn set_workers(&mut self, workers: NonZeroUsize) -> &mut Self {
+        self.workers = workers;
+
+        self
+    }
+
+    /// When set, part 1 and part 2 of a puzzle are solved as independent
+    /// jobs. The input is then parsed once per part.
+    pub fn set_split_parts(&mut self, split_parts: bool) -> &mut Self {
+        self.split_parts = split_parts;
+
+        self
+    }
+
+    /// Sets the time after which each run is abandoned
+    pub fn set_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Solves the requested parts of every job, returning one report per
+    /// job in the same order
+    pub fn run(&self, jobs: &[Job], execute: Execute) -> Vec<Report> {
+        let tasks = self.tasks(jobs, execute);
+        let workers = self.workers.get().min(tasks.len());
+        trace!("Solving {} task(s) with {} worker(s)", tasks.len(), workers);
+
+        let next_task = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let sender = sender.clone();
+                let next_task = &next_task;
+                let tasks = &tasks;
+
+                scope.spawn(move || loop {
+                    let index = next_task.fetch_add(1, Ordering::Relaxed);
+                    let Some(&(job, execute)) = tasks.get(index) else {
+                        break;
+                    };
+                    let job = &jobs[job];
+                    let report = solve_file(
+                        job.solver,
+                        execute,
+                        &job.input_path,
+                        self.timeout,
+                    );
+
+                    // The receiver outlives the scope, this cannot fail
+                    let _ = sender.send((index, report));
+                });
+            }
+        });
+        drop(sender);
+
+        let mut reports: Vec<Option<Report>> = vec![None; jobs.len()];
+        let mut finished: Vec<(usize, Report)> = receiver.into_iter().collect();
+        finished.sort_by_key(|(index, _)| *index);
+
+        for (index, report) in finished {
+            let (job, _) = tasks[index];
+            reports[job] = Some(match reports[job].take() {
+                Some(previous) => previous.merge(report),
+                None => report,
+            });
+        }
+
+        reports
+            .into_iter()
+            .map(|report| report.expect("every job has at least one task"))
+            .collect()
+    }
+
+    /// Splits the jobs into the tasks run by the workers, as pairs of job
+    /// index and parts to solve
+    fn tasks(&self, jobs: &[Job], execute: Execute) -> Vec<(usize, Execute)> {
+        let parts = match (self.split_parts, execute) {
+            (true, Execute::ALL) => vec![Execute::P1, Execute::P2],
+            _ => vec![execute],
+        };
+
+        (0..jobs.len())
+            .flat_map(|job| parts.iter().map(move |part| (job, *part)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::{Answer, Solution, SolverError, Timings};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn solve_echo(execute: Execute, input: &str) -> Report {
+        let solutions = execute.parts().map(|requested| match requested {
+            true => Solution::Value(Answer::Text(input.trim().to_string())),
+            false => Solution::NotExecuted,
+        });
+
+        Report {
+            solutions,
+            timings: Timings::default(),
+        }
+    }
+
+    fn input_file(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", content).unwrap();
+
+        file
+    }
+
+    fn text(value: &str) -> Solution {
+        Solution::Value(Answer::Text(value.to_string()))
+    }
+
+    #[test]
+    fn test_reports_keep_the_jobs_order() {
+        let files: Vec<NamedTempFile> =
+            (0..20).map(|i| input_file(&i.to_string())).collect();
+        let jobs: Vec<Job> = files
+            .iter()
+            .map(|file| Job {
+                solver: solve_echo,
+                input_path: file.path().to_path_buf(),
+            })
+            .collect();
+
+        let reports = Executor::default()
+            .set_workers(NonZeroUsize::new(4).unwrap())
+            .run(&jobs, Execute::ALL);
+
+        assert_eq!(reports.len(), 20);
+        for (i, report) in reports.iter().enumerate() {
+            assert_eq!(
+                report.solutions,
+                [text(&i.to_string()), text(&i.to_string())]
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_parts_are_merged() {
+        let file = input_file("42");
+        let jobs = [Job {
+            solver: solve_echo,
+            input_path: file.path().to_path_buf(),
+        }];
+
+        let reports = Executor::default()
+            .set_split_parts(true)
+            .run(&jobs, Execute::ALL);
+
+        assert_eq!(reports[0].solutions, [text("42"), text("42")]);
+    }
+
+    #[test]
+    fn test_split_parts_only_runs_requested_part() {
+        let file = input_file("42");
+        let jobs = [Job {
+            solver: solve_echo,
+            input_path: file.path().to_path_buf(),
+        }];
+
+        let reports = Executor::default()
+            .set_split_parts(true)
+            .run(&jobs, Execute::P2);
+
+        assert_eq!(reports[0].solutions, [Solution::NotExecuted, text("42")]);
+    }
+
+    #[test]
+    fn test_failures_do_not_stop_other_jobs() {
+        let file = input_file("42");
+        let jobs = [
+            Job {
+                solver: solve_echo,
+                input_path: PathBuf::from("this/file/does/not/exist.txt"),
+            },
+            Job {
+                solver: solve_echo,
+                input_path: file.path().to_path_buf(),
+            },
+        ];
+
+        let reports = Executor::default()
+            .set_workers(NonZeroUsize::MIN)
+            .run(&jobs, Execute::P1);
+
+        assert!(matches!(reports[0].first_error(), Some(SolverError::Io(_))));
+        assert_eq!(reports[1].solutions, [text("42"), Solution::NotExecuted]);
+    }
+
+    #[test]
+    fn test_no_jobs() {
+        assert!(Executor::default().run(&[], Execute::ALL).is_empty());
+    }
+}
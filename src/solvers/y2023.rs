@@ -1,6 +1,20 @@
-//! Advent of Code challenges for the day 2023
+//! Advent of Code challenges for the year 2023
+//!
+//! This file is maintained by `aoc generate`.
+use crate::solvers::SolveFn;
+
 pub mod day01;
 pub mod day02;
 pub mod day03;
 pub mod day04;
 pub mod day05;
+
+/// Registered solvers for 2023, indexed by day
+#[rustfmt::skip]
+pub const SOLVERS: &[(u32, SolveFn)] = &[
+    (1, day01::solve),
+    (2, day02::solve),
+    (3, day03::solve),
+    (4, day04::solve),
+    (5, day05::solve),
+];
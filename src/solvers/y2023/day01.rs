@@ -1,102 +1,128 @@
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use aho_corasick::AhoCorasick;
 use log::trace;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
-};
 
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 01 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day01>(execute, input)
 }
 
-fn solve_part1(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 1...");
+/// Solver for day 01
+pub struct Day01;
+
+impl Solver for Day01 {
+    /// Calibration document, one line per entry
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
+
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
 
-    let file =
-        File::open(input_path).map_err(|_| input_path.to_str().unwrap())?;
-    let reader = BufReader::new(file);
+        let mut parsed_numbers: Vec<u64> = Vec::new();
 
-    let mut parsed_numbers: Vec<u64> = Vec::new();
+        for line in lines {
+            let digits: Vec<u64> = line
+                .chars()
+                .filter(|c| c.is_ascii_digit())
+                .map(|c| c.to_digit(10).unwrap() as u64)
+                .collect();
 
-    for line in reader.lines() {
-        let line = line.expect("Failed to read line.");
+            if digits.is_empty() {
+                continue;
+            }
 
-        let digits: Vec<u64> = line
-            .chars()
-            .filter(|c| c.is_ascii_digit())
-            .map(|c| c.to_digit(10).unwrap() as u64)
-            .collect();
+            let first = digits.first().unwrap();
+            let last = digits.last().unwrap_or(first);
 
-        if digits.len() == 0 {
-            continue;
+            parsed_numbers.push(10 * first + last);
         }
 
-        let first = digits.first().unwrap();
-        let last = digits.last().unwrap_or(first);
+        let result: u64 = parsed_numbers.iter().sum();
 
-        parsed_numbers.push(10 * first + last);
+        Ok(SolutionExecution::Value(result.into()))
     }
 
-    let result = parsed_numbers.iter().sum();
+    fn part2(lines: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
+
+        let patterns = &[
+            "zero", "one", "two", "three", "four", "five", "six", "seven",
+            "eight", "nine", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9",
+        ];
+        let ac = AhoCorasick::new(patterns).unwrap();
+
+        let mut parsed_numbers: Vec<u64> = Vec::new();
+
+        for line in lines {
+            let digits: Vec<u64> = ac
+                .find_overlapping_iter(line)
+                .map(|m| line[m.start()..m.end()].to_string())
+                .map(|s| match s.as_str() {
+                    "zero" => "0".to_string(),
+                    "one" => "1".to_string(),
+                    "two" => "2".to_string(),
+                    "three" => "3".to_string(),
+                    "four" => "4".to_string(),
+                    "five" => "5".to_string(),
+                    "six" => "6".to_string(),
+                    "seven" => "7".to_string(),
+                    "eight" => "8".to_string(),
+                    "nine" => "9".to_string(),
+                    other => other.to_string(),
+                })
+                .map(|n| n.parse::<u64>().expect("Expected an integer!"))
+                .collect();
+
+            if digits.is_empty() {
+                continue;
+            }
+
+            let first = digits.first().expect("Expected at least one digit.");
+            let last = digits.last().unwrap_or(first);
+            let parsed_line_result = 10 * first + last;
+
+            parsed_numbers.push(parsed_line_result);
+        }
+
+        let result: u64 = parsed_numbers.iter().sum();
 
-    Ok(SolutionExecution::Value(result))
+        Ok(SolutionExecution::Value(result.into()))
+    }
 }
 
-fn solve_part2(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
-
-    let patterns = &[
-        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight",
-        "nine", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9",
-    ];
-    let ac = AhoCorasick::new(patterns).unwrap();
-
-    let file =
-        File::open(input_path).map_err(|_| input_path.to_str().unwrap())?;
-    let reader = BufReader::new(file);
-    let mut parsed_numbers: Vec<u64> = Vec::new();
-
-    for line in reader.lines() {
-        let line = line.expect("Failed to read line.");
-
-        let digits: Vec<u64> = ac
-            .find_overlapping_iter(&line)
-            .map(|m| line[m.start()..m.end()].to_string())
-            .map(|s| match s.as_str() {
-                "zero" => "0".to_string(),
-                "one" => "1".to_string(),
-                "two" => "2".to_string(),
-                "three" => "3".to_string(),
-                "four" => "4".to_string(),
-                "five" => "5".to_string(),
-                "six" => "6".to_string(),
-                "seven" => "7".to_string(),
-                "eight" => "8".to_string(),
-                "nine" => "9".to_string(),
-                other => other.to_string(),
-            })
-            .map(|n| n.parse::<u64>().expect("Expected an integer!"))
-            .collect();
-
-        if digits.len() == 0 {
-            continue;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2023/day01_01.example.txt"
+    ));
+    const EXAMPLE_02: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2023/day01_02.example.txt"
+    ));
 
-        let first = digits.first().expect("Expected at least one digit.");
-        let last = digits.last().unwrap_or(first);
-        let parsed_line_result = 10 * first + last;
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
 
-        parsed_numbers.push(parsed_line_result);
+        assert_eq!(report.solutions[0].to_string(), "142");
     }
 
-    let result = parsed_numbers.iter().sum();
+    #[test]
+    fn test_example_02_p2() {
+        let report = solve(Execute::P2, EXAMPLE_02);
 
-    Ok(SolutionExecution::Value(result))
+        assert_eq!(report.solutions[1].to_string(), "281");
+    }
 }
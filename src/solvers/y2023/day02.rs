@@ -1,17 +1,14 @@
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use log::trace;
 use logos::{Lexer, Logos};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
 
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 02 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day02>(execute, input)
 }
 
 #[derive(Logos, Debug)]
@@ -38,110 +35,133 @@ fn parse_game(lex: &mut Lexer<Token>) -> Option<u64> {
 }
 
 fn parse_color(lex: &mut Lexer<Token>) -> Option<u64> {
-    let number = lex.slice().split_whitespace().nth(0)?;
+    let number = lex.slice().split_whitespace().next()?;
     let parsed_number = number.parse().ok()?;
 
     Some(parsed_number)
 }
 
-fn solve_part1(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 1...");
-
-    let file = File::open(input_path)
-        .map_err(|e| format!("cannot open input file {}", e))?;
-    let reader = BufReader::new(file);
+/// A game, with every handful of cubes revealed during it
+#[derive(Debug)]
+pub struct Game {
+    id: u64,
+    cubes: Vec<Token>,
+}
 
-    const MAX_RED_CUBES: u64 = 12;
-    const MAX_GREEN_CUBES: u64 = 13;
-    const MAX_BLUE_CUBES: u64 = 14;
+/// Solver for day 02
+pub struct Day02;
 
-    let mut result: u64 = 0;
+impl Solver for Day02 {
+    type Input = Vec<Game>;
 
-    for line in reader.lines() {
-        let line = line.map_err(|e| format!("cannot read line: {}", e))?;
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
 
-        let mut game_id = None;
+        let mut games = Vec::new();
 
-        for token in Token::lexer(&line) {
-            let token = token.map_err(|_| {
-                format!("Error while parsing the line: {}", line)
-            })?;
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
 
-            match token {
-                Token::Game(id) => game_id = Some(id),
-                Token::Red(cubes) => {
-                    if cubes > MAX_RED_CUBES {
-                        game_id = None;
-                        break;
-                    }
-                }
-                Token::Green(cubes) => {
-                    if cubes > MAX_GREEN_CUBES {
-                        game_id = None;
-                        break;
-                    }
-                }
-                Token::Blue(cubes) => {
-                    if cubes > MAX_BLUE_CUBES {
-                        game_id = None;
-                        break;
-                    }
+            let mut game_id = None;
+            let mut cubes = Vec::new();
+
+            for (token, span) in Token::lexer(line).spanned() {
+                let token = token.map_err(|_| {
+                    SolverError::parse_field(
+                        index + 1,
+                        line,
+                        &line[span],
+                        "Unexpected token",
+                    )
+                })?;
+
+                match token {
+                    Token::Game(id) => game_id = Some(id),
+                    cubes_token => cubes.push(cubes_token),
                 }
             }
-        }
 
-        if let Some(game_id) = game_id {
-            result += game_id;
+            let id = game_id.ok_or_else(|| {
+                SolverError::parse(index + 1, 1, line, "Missing the game id")
+            })?;
+            games.push(Game { id, cubes });
         }
-    }
-
-    Ok(SolutionExecution::Value(result))
-}
-
-fn solve_part2(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
 
-    let file = File::open(input_path)
-        .map_err(|e| format!("cannot open input file {}", e))?;
-    let reader = BufReader::new(file);
+        Ok(games)
+    }
 
-    let mut result = 0;
+    fn part1(games: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
+
+        const MAX_RED_CUBES: u64 = 12;
+        const MAX_GREEN_CUBES: u64 = 13;
+        const MAX_BLUE_CUBES: u64 = 14;
+
+        let result: u64 = games
+            .iter()
+            .filter(|game| {
+                game.cubes.iter().all(|token| match token {
+                    Token::Red(cubes) => *cubes <= MAX_RED_CUBES,
+                    Token::Green(cubes) => *cubes <= MAX_GREEN_CUBES,
+                    Token::Blue(cubes) => *cubes <= MAX_BLUE_CUBES,
+                    Token::Game(_) => true,
+                })
+            })
+            .map(|game| game.id)
+            .sum();
+
+        Ok(SolutionExecution::Value(result.into()))
+    }
 
-    for line in reader.lines() {
-        let line = line.map_err(|e| format!("cannot read line: {}", e))?;
+    fn part2(games: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
 
-        let mut max_red = 0;
-        let mut max_green = 0;
-        let mut max_blue = 0;
+        let mut result = 0;
 
-        for token in Token::lexer(&line) {
-            let token = token.map_err(|_| {
-                format!("Error while parsing the line: {}", line)
-            })?;
+        for game in games {
+            let mut max_red = 0;
+            let mut max_green = 0;
+            let mut max_blue = 0;
 
-            match token {
-                Token::Red(cubes) => {
-                    if cubes > max_red {
-                        max_red = cubes;
-                    }
-                }
-                Token::Green(cubes) => {
-                    if cubes > max_green {
-                        max_green = cubes;
-                    }
-                }
-                Token::Blue(cubes) => {
-                    if cubes > max_blue {
-                        max_blue = cubes;
-                    }
+            for token in &game.cubes {
+                match *token {
+                    Token::Red(cubes) => max_red = max_red.max(cubes),
+                    Token::Green(cubes) => max_green = max_green.max(cubes),
+                    Token::Blue(cubes) => max_blue = max_blue.max(cubes),
+                    Token::Game(_) => {}
                 }
-                Token::Game(_) => {}
             }
+
+            let power_cube = max_red * max_green * max_blue;
+            result += power_cube;
         }
 
-        let power_cube = max_red * max_green * max_blue;
-        result += power_cube;
+        Ok(SolutionExecution::Value(result.into()))
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(SolutionExecution::Value(result))
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2023/day02_01.example.txt"
+    ));
+
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
+
+        assert_eq!(report.solutions[0].to_string(), "8");
+    }
+
+    #[test]
+    fn test_example_01_p2() {
+        let report = solve(Execute::P2, EXAMPLE_01);
+
+        assert_eq!(report.solutions[1].to_string(), "2286");
+    }
 }
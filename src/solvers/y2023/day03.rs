@@ -6,28 +6,26 @@
 
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use log::trace;
 use logos::{Lexer, Logos, Skip};
-use std::fs;
 use std::ops::Range;
-use std::path::Path;
 
 /// Solves both parts of day 3's puzzle
 ///
 /// # Arguments
 ///
 /// * `execute` - Execution mode configuration
-/// * `input_path` - Path to the input file containing the schematic
+/// * `input` - Puzzle input containing the schematic
 ///
 /// # Returns
 ///
-/// Array containing solutions for both parts of the puzzle
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+/// Report containing the solutions for both parts of the puzzle
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 03 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day03>(execute, input)
 }
 
 /// Represents the position of a number token in the schematic
@@ -268,95 +266,128 @@ fn gear_callback(lexer: &Lexer<Token>) -> GearToken {
     GearToken::new(SymbolPosition::new(row, col_start))
 }
 
-/// Solves part 1 of the puzzle
-///
-/// Finds all numbers that are adjacent to symbols and sums them
-///
-/// # Arguments
-///
-/// * `input_path` - Path to the input file
-///
-/// # Returns
-///
-/// Result containing the sum of all numbers adjacent to symbols
-fn solve_part1(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 1...");
+/// Numbers and symbols found in the schematic
+#[derive(Debug, Default)]
+pub struct Schematic {
+    /// Every number, with its position
+    numbers: Vec<NumberToken>,
 
-    let mut symbols: Vec<SymbolPosition> = Vec::new();
-    let mut numbers: Vec<NumberToken> = Vec::new();
+    /// Position of every symbol, gears included
+    symbols: Vec<SymbolPosition>,
 
-    let data = fs::read_to_string(input_path).map_err(|e| e.to_string())?;
-
-    for token in Token::lexer(&data) {
-        let token = token.map_err(|_| "Failed to parse data")?;
+    /// Position of every gear
+    gears: Vec<SymbolPosition>,
+}
 
-        match token {
-            Token::Number(data) => numbers.push(data),
-            Token::Symbol(data) => symbols.push(data.position),
-            Token::Gear(data) => symbols.push(data.position),
-            _ => {}
+/// Solver for day 03
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Schematic;
+
+    /// Lexes the schematic, collecting its numbers, symbols and gears
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
+
+        let mut schematic = Schematic::default();
+
+        for (token, span) in Token::lexer(input).spanned() {
+            let token = token.map_err(|_| {
+                SolverError::parse_at_offset(
+                    input,
+                    span.start,
+                    "Failed to parse data",
+                )
+            })?;
+
+            match token {
+                Token::Number(data) => schematic.numbers.push(data),
+                Token::Symbol(data) => schematic.symbols.push(data.position),
+                Token::Gear(data) => {
+                    schematic.symbols.push(data.position.clone());
+                    schematic.gears.push(data.position);
+                }
+                _ => {}
+            }
         }
-    }
 
-    let mut result = 0;
+        Ok(schematic)
+    }
 
-    'outer: for number in numbers {
-        for symbol in &symbols {
-            if number.is_in_range(symbol) {
-                result += number.value;
-                continue 'outer;
+    /// Solves part 1 of the puzzle
+    ///
+    /// Finds all numbers that are adjacent to symbols and sums them
+    fn part1(
+        schematic: &Self::Input,
+    ) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
+
+        let mut result = 0;
+
+        'outer: for number in &schematic.numbers {
+            for symbol in &schematic.symbols {
+                if number.is_in_range(symbol) {
+                    result += number.value;
+                    continue 'outer;
+                }
             }
         }
+
+        Ok(SolutionExecution::Value(result.into()))
     }
 
-    Ok(SolutionExecution::Value(result))
-}
+    /// Solves part 2 of the puzzle by calculating the product of numbers
+    /// adjacent to gears
+    fn part2(
+        schematic: &Self::Input,
+    ) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
 
-/// Solves part 2 of the puzzle by calculating the product of numbers adjacent to gears
-///
-/// # Arguments
-///
-/// * `input_path` - Path to the input file
-///
-/// # Returns
-///
-/// Result indicating the solution is not implemented
-fn solve_part2(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
-
-    let data = fs::read_to_string(input_path).map_err(|e| e.to_string())?;
-    let mut gears: Vec<SymbolPosition> = Vec::new();
-    let mut numbers: Vec<NumberToken> = Vec::new();
-    let mut result = 0;
-
-    for token in Token::lexer(&data) {
-        let token = token.map_err(|_| "Failed to parse data")?;
-
-        match token {
-            Token::Number(data) => numbers.push(data),
-            Token::Gear(data) => gears.push(data.position),
-            _ => {}
-        }
-    }
+        let mut result = 0;
 
-    for gear in gears {
-        let mut numbers_in_range: Vec<u64> = Vec::new();
+        for gear in &schematic.gears {
+            let mut numbers_in_range: Vec<u64> = Vec::new();
 
-        for number in &numbers {
-            if number.is_in_range(&gear) {
-                numbers_in_range.push(number.value);
+            for number in &schematic.numbers {
+                if number.is_in_range(gear) {
+                    numbers_in_range.push(number.value);
+                }
+
+                if numbers_in_range.len() > 2 {
+                    result += number.value;
+                    break;
+                }
             }
 
-            if numbers_in_range.len() > 2 {
-                result += number.value;
-                break;
+            if numbers_in_range.len() == 2 {
+                result += numbers_in_range.iter().product::<u64>();
             }
         }
 
-        if numbers_in_range.len() == 2 {
-            result += numbers_in_range.iter().fold(1, |acc, x| acc * x);
-        }
+        Ok(SolutionExecution::Value(result.into()))
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2023/day03_01.example.txt"
+    ));
+
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
 
-    Ok(SolutionExecution::Value(result))
+        assert_eq!(report.solutions[0].to_string(), "4361");
+    }
+
+    #[test]
+    fn test_example_01_p2() {
+        let report = solve(Execute::P2, EXAMPLE_01);
+
+        assert_eq!(report.solutions[1].to_string(), "467835");
+    }
 }
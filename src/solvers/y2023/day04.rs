@@ -9,29 +9,26 @@
 
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use log::trace;
 use logos::Logos;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
 
 /// Solves both parts of day 04's puzzle
 ///
 /// # Arguments
 ///
 /// * `execute` - Execution mode configuration
-/// * `input_path` - Path to the input file containing the schematic
+/// * `input` - Puzzle input containing the schematic
 ///
 /// # Returns
 ///
-/// Array containing solutions for both parts of the puzzle
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+/// Report containing the solutions for both parts of the puzzle
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 04 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day04>(execute, input)
 }
 
 /// Enum representing the lexical tokens for the scratchcard game.
@@ -67,7 +64,7 @@ fn card_callback(lexer: &logos::Lexer<Token>) -> Option<u32> {
 
     slice
         .chars()
-        .filter(|c| c.is_digit(10))
+        .filter(|c| c.is_ascii_digit())
         .collect::<String>()
         .parse()
         .ok()
@@ -78,109 +75,167 @@ fn number_callback(lexer: &logos::Lexer<Token>) -> Option<u32> {
     lexer.slice().parse().ok()
 }
 
-/// Solution to part 1.
-/// # Challenge
-///
-/// Read a set of cards, and return the sum of all the card values.
-/// A card value is defined as:
-///
-/// * 1, for the first played number in the winning set
-/// * For each remaining number, multiply the value by 2
-fn solve_part1(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 1...");
-
-    let file = File::open(input_path).map_err(|e| e.to_string())?;
-    let reader = BufReader::new(file);
-    let mut result = 0;
-
-    for line in reader.lines() {
-        let line = line.map_err(|e| e.to_string())?;
-        let mut reading_status = ReadingStatus::ReadingWinningNumbers;
-        let mut winning_numbers = Vec::new();
-        let mut card_value = 0;
-
-        for token in Token::lexer(&line) {
-            let token = token.map_err(|_| {
-                format!("Unable to parse token in line: {}", line)
-            })?;
+/// A scratchcard, with the numbers on each side of the separator
+#[derive(Debug, PartialEq, Eq)]
+pub struct Card {
+    /// Card number
+    id: u32,
+
+    /// Winning numbers, before the separator
+    winning_numbers: Vec<u32>,
+
+    /// Played numbers, after the separator
+    played_numbers: Vec<u32>,
+}
+
+/// Solver for day 04
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
+
+        let mut cards = Vec::new();
 
-            match token {
-                Token::Separator => {
-                    reading_status = ReadingStatus::MatchingNumbers
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut reading_status = ReadingStatus::ReadingWinningNumbers;
+            let mut current_card = None;
+            let mut winning_numbers = Vec::new();
+            let mut played_numbers = Vec::new();
+
+            for (token, span) in Token::lexer(line).spanned() {
+                let token = token.map_err(|_| {
+                    SolverError::parse_field(
+                        index + 1,
+                        line,
+                        &line[span],
+                        "Unable to parse token",
+                    )
+                })?;
+
+                match token {
+                    Token::Card(card) => current_card = Some(card),
+                    Token::Separator => {
+                        reading_status = ReadingStatus::MatchingNumbers
+                    }
+                    Token::Number(n) => match reading_status {
+                        ReadingStatus::ReadingWinningNumbers => {
+                            winning_numbers.push(n)
+                        }
+                        ReadingStatus::MatchingNumbers => {
+                            played_numbers.push(n)
+                        }
+                    },
                 }
-                Token::Number(n) => {
-                    if reading_status == ReadingStatus::ReadingWinningNumbers {
-                        winning_numbers.push(n);
+            }
+
+            let id = current_card.ok_or_else(|| {
+                SolverError::parse(
+                    index + 1,
+                    1,
+                    line,
+                    "Error parsing the card number",
+                )
+            })?;
+
+            cards.push(Card {
+                id,
+                winning_numbers,
+                played_numbers,
+            });
+        }
+
+        Ok(cards)
+    }
+
+    /// Solution to part 1.
+    /// # Challenge
+    ///
+    /// Read a set of cards, and return the sum of all the card values.
+    /// A card value is defined as:
+    ///
+    /// * 1, for the first played number in the winning set
+    /// * For each remaining number, multiply the value by 2
+    fn part1(cards: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
+
+        let mut result: u64 = 0;
+
+        for card in cards {
+            let mut card_value = 0;
+
+            for n in &card.played_numbers {
+                if card.winning_numbers.contains(n) {
+                    if card_value == 0 {
+                        card_value = 1;
                     } else {
-                        if winning_numbers.contains(&n) {
-                            if card_value == 0 {
-                                card_value = 1;
-                            } else {
-                                card_value *= 2;
-                            }
-                        }
+                        card_value *= 2;
                     }
                 }
-                Token::Card(_) => (),
             }
+
+            result += card_value;
         }
 
-        result += card_value;
+        Ok(SolutionExecution::Value(result.into()))
     }
 
-    Ok(SolutionExecution::Value(result))
-}
+    fn part2(cards: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
 
-fn solve_part2(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
+        let mut scratchboards = HashMap::new();
 
-    let file = File::open(input_path).map_err(|e| e.to_string())?;
-    let reader = BufReader::new(file);
-    let mut scratchboards = HashMap::new();
+        for card in cards {
+            let mut winning_numbers: HashSet<&u32> =
+                card.winning_numbers.iter().collect();
+            let mut matched_numbers = 0;
 
-    for line in reader.lines() {
-        let line = line.map_err(|e| e.to_string())?;
-        let mut reading_status = ReadingStatus::ReadingWinningNumbers;
-        let mut winning_numbers = HashSet::new();
-        let mut current_card = None;
-        let mut matched_numbers = 0;
+            for n in &card.played_numbers {
+                if winning_numbers.remove(n) {
+                    matched_numbers += 1;
+                }
+            }
 
-        for token in Token::lexer(&line) {
-            let token = token.map_err(|_| {
-                format!("Unable to parse token in line: {}", line)
-            })?;
+            let copies = scratchboards.entry(card.id).or_insert(0);
+            *copies += 1;
+            let copies = *copies;
 
-            match token {
-                Token::Card(card) => current_card = Some(card),
-                Token::Separator => {
-                    reading_status = ReadingStatus::MatchingNumbers
-                }
-                Token::Number(n) => match reading_status {
-                    ReadingStatus::ReadingWinningNumbers => {
-                        winning_numbers.insert(n);
-                    }
-                    ReadingStatus::MatchingNumbers => {
-                        if winning_numbers.contains(&n) {
-                            winning_numbers.remove(&n);
-                            matched_numbers += 1;
-                        }
-                    }
-                },
+            for card_number in card.id + 1..=card.id + matched_numbers {
+                *scratchboards.entry(card_number).or_insert(0) += copies;
             }
         }
 
-        let current_card = current_card
-            .ok_or(format!("Error parsing the card number on line: {line}"))?;
+        let result: u64 = scratchboards.values().sum();
+        Ok(SolutionExecution::Value(result.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let copies = scratchboards.entry(current_card).or_insert(0);
-        *copies += 1;
-        let copies = *copies;
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2023/day04_01.example.txt"
+    ));
 
-        for card_number in current_card + 1..=current_card + matched_numbers {
-            *scratchboards.entry(card_number).or_insert(0) += copies;
-        }
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
+
+        assert_eq!(report.solutions[0].to_string(), "13");
     }
 
-    let result = scratchboards.values().sum();
-    Ok(SolutionExecution::Value(result))
+    #[test]
+    fn test_example_01_p2() {
+        let report = solve(Execute::P2, EXAMPLE_01);
+
+        assert_eq!(report.solutions[1].to_string(), "30");
+    }
 }
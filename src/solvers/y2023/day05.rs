@@ -1,28 +1,25 @@
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use log::trace;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Debug, Formatter};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
 
 /// Solves both parts of day 05's puzzle
 ///
 /// # Arguments
 ///
 /// * `execute` - Execution mode configuration
-/// * `input_path` - Path to the input file containing the schematic
+/// * `input` - Puzzle input containing the schematic
 ///
 /// # Returns
 ///
-/// Array containing solutions for both parts of the puzzle
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+/// Report containing the solutions for both parts of the puzzle
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 05 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day05>(execute, input)
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
@@ -38,7 +35,7 @@ impl Debug for SeedRange {
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct FileData {
+pub struct FileData {
     seeds: Vec<u64>,
     seed_ranges: Vec<SeedRange>,
     almanac: HashMap<String, Vec<AlmanacEntry>>,
@@ -64,10 +61,7 @@ impl FileData {
     }
 
     fn add_almanac_entry(&mut self, target: String, entry: AlmanacEntry) {
-        self.almanac
-            .entry(target)
-            .or_insert_with(Vec::new)
-            .push(entry);
+        self.almanac.entry(target).or_default().push(entry);
     }
 
     fn add_entity(&mut self, entity: String) {
@@ -86,25 +80,29 @@ impl FileData {
         self.seed_ranges.push(range);
     }
 
-    fn apply(&self, seed: u64) -> Result<u64, String> {
+    fn apply(&self, seed: u64) -> Result<u64, SolverError> {
         let mut current = "seed";
         let mut result = seed;
 
         while current != "location" {
-            let current_key = self
-                .almanac_keys
-                .get(current)
-                .ok_or(format!("No key for {current}"))?;
-
-            let current_entries = self
-                .almanac
-                .get(current_key)
-                .ok_or(format!("No almanac entries for {current}"))?;
-
-            let current_destination = self
-                .destination
-                .get(current_key)
-                .ok_or(format!("No destination for {current}"))?;
+            let current_key =
+                self.almanac_keys.get(current).ok_or_else(|| {
+                    SolverError::InvalidInput(format!("No key for {current}"))
+                })?;
+
+            let current_entries =
+                self.almanac.get(current_key).ok_or_else(|| {
+                    SolverError::InvalidInput(format!(
+                        "No almanac entries for {current}"
+                    ))
+                })?;
+
+            let current_destination =
+                self.destination.get(current_key).ok_or_else(|| {
+                    SolverError::InvalidInput(format!(
+                        "No destination for {current}"
+                    ))
+                })?;
 
             current = current_destination;
             for entry in current_entries {
@@ -112,7 +110,7 @@ impl FileData {
                 let destination = entry.1;
                 let range_length = entry.2;
 
-                if result >= source && result <= source + range_length - 1 {
+                if result >= source && result < source + range_length {
                     result =
                         destination.wrapping_sub(source).wrapping_add(result);
                     break;
@@ -124,23 +122,20 @@ impl FileData {
     }
 }
 
-fn parse_file(input_path: &Path) -> Result<FileData, String> {
+fn parse_file(input: &str) -> Result<FileData, SolverError> {
     let mut result = FileData::new();
-    let file = File::open(input_path).map_err(|e| e.to_string())?;
-    let buffer = BufReader::new(file);
-
     let mut current_target = None;
 
-    for line in buffer.lines() {
-        let line = line.map_err(|e| e.to_string())?;
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
         let line = line.trim();
 
         if line.is_empty() {
             continue;
         }
 
-        if line.starts_with("seeds: ") {
-            let seeds: Vec<u64> = line[7..]
+        if let Some(seeds) = line.strip_prefix("seeds: ") {
+            let seeds: Vec<u64> = seeds
                 .split_whitespace()
                 .filter_map(|s| s.parse().ok())
                 .collect();
@@ -158,27 +153,31 @@ fn parse_file(input_path: &Path) -> Result<FileData, String> {
         }
 
         if line.contains("map:") {
-            let line = line
-                .split_once(" ")
-                .ok_or(format!("Failed to split line: {line}"))?;
-            current_target = Some(line.0.to_string());
+            let header = line.split_once(" ").ok_or_else(|| {
+                SolverError::parse(line_number, 1, line, "Failed to split line")
+            })?;
+            current_target = Some(header.0.to_string());
 
-            let entities = line
-                .0
-                .split_once("-to-")
-                .ok_or(format!("Invalid format: {}", line.0))?;
+            let entities = header.0.split_once("-to-").ok_or_else(|| {
+                SolverError::parse(line_number, 1, line, "Invalid format")
+            })?;
 
             result.add_entity(entities.0.to_string());
             result.add_entity(entities.1.to_string());
-            result.add_almanac_key(entities.0.to_string(), line.0.to_owned());
-            result.add_destination(line.0.to_owned(), entities.1.to_string());
+            result.add_almanac_key(entities.0.to_string(), header.0.to_owned());
+            result.add_destination(header.0.to_owned(), entities.1.to_string());
 
             continue;
         }
 
         match current_target {
             None => {
-                return Err(format!("Expected target before {line}"));
+                return Err(SolverError::parse(
+                    line_number,
+                    1,
+                    line,
+                    "Expected target before this line",
+                ));
             }
             Some(ref target) => {
                 let values: Vec<u64> = line
@@ -187,7 +186,12 @@ fn parse_file(input_path: &Path) -> Result<FileData, String> {
                     .collect();
 
                 if values.len() != 3 {
-                    return Err(format!("Expected three values from: {line}"));
+                    return Err(SolverError::parse(
+                        line_number,
+                        1,
+                        line,
+                        "Expected three values",
+                    ));
                 }
 
                 let destination_start = values[0];
@@ -205,70 +209,110 @@ fn parse_file(input_path: &Path) -> Result<FileData, String> {
     Ok(result)
 }
 
-fn solve_part1(input_path: &Path) -> Result<SolutionExecution, String> {
-    let file_data = parse_file(input_path)?;
+/// Solver for day 05
+pub struct Day05;
 
-    let locations: Result<Vec<u64>, String> = file_data
-        .seeds
-        .iter()
-        .map(|seed| file_data.apply(*seed))
-        .collect();
-    let locations = locations?;
+impl Solver for Day05 {
+    type Input = FileData;
 
-    let result = locations
-        .iter()
-        .min()
-        .expect("At least one location should exists");
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
 
-    Ok(SolutionExecution::Value(*result))
-}
+        parse_file(input)
+    }
+
+    fn part1(
+        file_data: &Self::Input,
+    ) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
 
-fn solve_part2(input_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
+        let locations: Result<Vec<u64>, SolverError> = file_data
+            .seeds
+            .iter()
+            .map(|seed| file_data.apply(*seed))
+            .collect();
+        let locations = locations?;
 
-    let file_data = parse_file(input_path)?;
-    let mut queue = VecDeque::new();
-    let mut result = u64::MAX;
+        let result = locations
+            .iter()
+            .min()
+            .expect("At least one location should exists");
 
-    for seed_range in file_data.seed_ranges.iter() {
-        queue.push_back(*seed_range);
+        Ok(SolutionExecution::Value((*result).into()))
     }
 
-    while let Some(seed_range) = queue.pop_front() {
-        let seed_start = seed_range.0;
-        let seed_length = seed_range.1;
-        let seed_end = seed_start + seed_length - 1;
-
-        let v0 = file_data.apply(seed_start)?;
-        let v1 = file_data.apply(seed_end)?;
-
-        if seed_length > 2
-            && v1.wrapping_sub(v0) != seed_length - 1
-            && v0.wrapping_sub(v1) != seed_length - 1
-        {
-            let midpoint = (seed_start + seed_end) / 2;
-            let offset = match seed_length % 2 {
-                0 => 1,
-                1 => 0,
-                _ => unreachable!(),
-            };
-
-            queue.push_back(SeedRange(seed_start, seed_length / 2));
-            queue.push_back(SeedRange(
-                midpoint + offset,
-                seed_length / 2 + seed_length % 2,
-            ));
-            continue;
-        }
+    fn part2(
+        file_data: &Self::Input,
+    ) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
+
+        let mut queue = VecDeque::new();
+        let mut result = u64::MAX;
 
-        if v0 < result {
-            result = v0;
+        for seed_range in file_data.seed_ranges.iter() {
+            queue.push_back(*seed_range);
         }
 
-        if v1 < result {
-            result = v1;
+        while let Some(seed_range) = queue.pop_front() {
+            let seed_start = seed_range.0;
+            let seed_length = seed_range.1;
+            let seed_end = seed_start + seed_length - 1;
+
+            let v0 = file_data.apply(seed_start)?;
+            let v1 = file_data.apply(seed_end)?;
+
+            if seed_length > 2
+                && v1.wrapping_sub(v0) != seed_length - 1
+                && v0.wrapping_sub(v1) != seed_length - 1
+            {
+                let midpoint = (seed_start + seed_end) / 2;
+                let offset = match seed_length % 2 {
+                    0 => 1,
+                    1 => 0,
+                    _ => unreachable!(),
+                };
+
+                queue.push_back(SeedRange(seed_start, seed_length / 2));
+                queue.push_back(SeedRange(
+                    midpoint + offset,
+                    seed_length / 2 + seed_length % 2,
+                ));
+                continue;
+            }
+
+            if v0 < result {
+                result = v0;
+            }
+
+            if v1 < result {
+                result = v1;
+            }
         }
+
+        Ok(SolutionExecution::Value(result.into()))
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2023/day05_01.example.txt"
+    ));
 
-    Ok(SolutionExecution::Value(result))
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
+
+        assert_eq!(report.solutions[0].to_string(), "35");
+    }
+
+    #[test]
+    fn test_example_01_p2() {
+        let report = solve(Execute::P2, EXAMPLE_01);
+
+        assert_eq!(report.solutions[1].to_string(), "46");
+    }
 }
@@ -1,2 +1,14 @@
+//! Advent of Code challenges for the year 2024
+//!
+//! This file is maintained by `aoc generate`.
+use crate::solvers::SolveFn;
+
 pub mod day01;
 pub mod day02;
+
+/// Registered solvers for 2024, indexed by day
+#[rustfmt::skip]
+pub const SOLVERS: &[(u32, SolveFn)] = &[
+    (1, day01::solve),
+    (2, day02::solve),
+];
@@ -1,98 +1,134 @@
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use log::trace;
 use std::collections::HashMap;
-use std::fs;
-use std::path::Path;
 
 /// Solves both parts of day 01's puzzle
 ///
 /// # Arguments
 ///
 /// * `execute` - Execution mode configuration
-/// * `input_path` - Path to the input file containing the schematic
+/// * `input` - Puzzle input containing the location lists
 ///
 /// # Returns
 ///
-/// Array containing solutions for both parts of the puzzle
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+/// Report containing the solutions for both parts of the puzzle
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 01 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day01>(execute, input)
 }
 
-fn solve_part1(path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 1...");
-    let data = fs::read_to_string(path).map_err(|e| e.to_string())?;
-    let line_count = data.matches("\n").count() + 1;
-
-    let mut list_a = Vec::with_capacity(line_count);
-    let mut list_b = Vec::with_capacity(line_count);
-
-    for line in data.lines() {
-        let fields: Vec<String> =
-            line.split_whitespace().map(|s| s.to_string()).collect();
+/// The two location lists, read column by column
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lists {
+    left: Vec<u64>,
+    right: Vec<u64>,
+}
 
-        if fields.len() != 2 {
-            return Err(format!("Invalid line: {}", line));
+/// Solver for day 01
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
+
+        let line_count = input.matches("\n").count() + 1;
+        let mut left = Vec::with_capacity(line_count);
+        let mut right = Vec::with_capacity(line_count);
+
+        for (index, line) in input.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            if fields.len() != 2 {
+                return Err(SolverError::parse(
+                    index + 1,
+                    1,
+                    line,
+                    "Expected two numbers",
+                ));
+            }
+
+            let parse_number = |field: &str| {
+                field.parse::<u64>().map_err(|_| {
+                    SolverError::parse_field(
+                        index + 1,
+                        line,
+                        field,
+                        "Invalid number",
+                    )
+                })
+            };
+            let a = parse_number(fields[0])?;
+            let b = parse_number(fields[1])?;
+
+            left.push(a);
+            right.push(b);
         }
 
-        let a: i64 = fields[0]
-            .parse()
-            .map_err(|_| format!("Invalid number: {}", fields[0]))?;
-        let b: i64 = fields[1]
-            .parse()
-            .map_err(|_| format!("Invalid number: {}", fields[1]))?;
-
-        list_a.push(a);
-        list_b.push(b);
+        Ok(Lists { left, right })
     }
 
-    list_a.sort();
-    list_b.sort();
+    fn part1(lists: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
 
-    let result: i64 = list_a
-        .into_iter()
-        .zip(list_b.into_iter())
-        .fold(0, |acc, (a, b)| acc + (a - b).abs());
+        let mut list_a = lists.left.clone();
+        let mut list_b = lists.right.clone();
 
-    Ok(SolutionExecution::Value(result as u64))
-}
+        list_a.sort();
+        list_b.sort();
 
-fn solve_part2(path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
+        let result: u64 = list_a
+            .into_iter()
+            .zip(list_b)
+            .fold(0, |acc, (a, b)| acc + a.abs_diff(b));
 
-    let data = fs::read_to_string(path).map_err(|e| e.to_string())?;
-    let line_count = data.matches("\n").count() + 1;
+        Ok(SolutionExecution::Value(result.into()))
+    }
 
-    let mut list_a = Vec::with_capacity(line_count);
-    let mut occurrences = HashMap::new();
+    fn part2(lists: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
 
-    for line in data.lines() {
-        let fields: Vec<String> =
-            line.split_whitespace().map(|s| s.to_string()).collect();
+        let mut occurrences = HashMap::new();
 
-        if fields.len() != 2 {
-            return Err(format!("Invalid line: {}", line));
+        for b in &lists.right {
+            occurrences.entry(b).and_modify(|v| *v += 1).or_insert(1u64);
         }
 
-        let a: u64 = fields[0]
-            .parse()
-            .map_err(|_| format!("Invalid number: {}", fields[0]))?;
-        let b: u64 = fields[1]
-            .parse()
-            .map_err(|_| format!("Invalid number: {}", fields[1]))?;
+        let score: u64 = lists
+            .left
+            .iter()
+            .map(|a| a * occurrences.get(a).unwrap_or(&0))
+            .sum();
 
-        list_a.push(a);
-        occurrences.entry(b).and_modify(|v| *v += 1).or_insert(1u64);
+        Ok(SolutionExecution::Value(score.into()))
     }
+}
 
-    let score: u64 = list_a
-        .into_iter()
-        .map(|a| a * occurrences.get(&a).unwrap_or(&0))
-        .sum();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(SolutionExecution::Value(score))
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2024/day01_01.example.txt"
+    ));
+
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
+
+        assert_eq!(report.solutions[0].to_string(), "11");
+    }
+
+    #[test]
+    fn test_example_01_p2() {
+        let report = solve(Execute::P2, EXAMPLE_01);
+
+        assert_eq!(report.solutions[1].to_string(), "31");
+    }
 }
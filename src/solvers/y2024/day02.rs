@@ -1,25 +1,23 @@
 use crate::{
     solvers,
-    solvers::{Solution, SolutionExecution},
+    solvers::{Report, SolutionExecution, Solver, SolverError},
     Execute,
 };
 use log::trace;
-use std::fs;
-use std::path::Path;
 
 /// Solves both parts of day 02's puzzle
 ///
 /// # Arguments
 ///
 /// * `execute` - Execution mode configuration
-/// * `input_path` - Path to the input file containing the schematic
+/// * `input` - Puzzle input containing the reports
 ///
 /// # Returns
 ///
-/// Array containing solutions for both parts of the puzzle
-pub fn solve(execute: Execute, input_path: &Path) -> [Solution; 2] {
+/// Report containing the solutions for both parts of the puzzle
+pub fn solve(execute: Execute, input: &str) -> Report {
     trace!("Running solver for day 02 with Execute {}...", execute);
-    solvers::common_solve(execute, input_path, solve_part1, solve_part2)
+    solvers::common_solve::<Day02>(execute, input)
 }
 
 fn are_levels_safe(levels: &[u64]) -> bool {
@@ -38,49 +36,75 @@ fn are_levels_safe(levels: &[u64]) -> bool {
         .zip(levels.iter().skip(1))
         .all(|(&a, &b)| a > b);
 
-    let are_all_levels_valid = have_all_levels_max_step_three
-        && (are_all_levels_ascending || are_all_levels_descending);
-
-    are_all_levels_valid
+    have_all_levels_max_step_three
+        && (are_all_levels_ascending || are_all_levels_descending)
 }
 
-fn solve_part1(path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 1...");
-
-    let data = fs::read_to_string(path).map_err(|e| e.to_string())?;
-    let mut result = 0;
-
-    for line in data.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
+/// Solver for day 02
+pub struct Day02;
+
+impl Solver for Day02 {
+    /// Reports, each one with its levels
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        trace!("Parsing input...");
+
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                line.split_whitespace()
+                    .map(|field| {
+                        field.parse::<u64>().map_err(|e| {
+                            SolverError::parse_field(
+                                index + 1,
+                                line,
+                                field,
+                                e.to_string(),
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 
-        let fields: Result<Vec<u64>, String> = line
-            .split_whitespace()
-            .map(|s| s.parse::<u64>().map_err(|e| e.to_string()))
-            .collect();
+    fn part1(reports: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 1...");
 
-        let fields = fields?;
+        let result = reports
+            .iter()
+            .filter(|levels| are_levels_safe(levels))
+            .count() as u64;
 
-        if are_levels_safe(&fields) {
-            result += 1;
-        }
+        Ok(SolutionExecution::Value(result.into()))
     }
 
-    Ok(SolutionExecution::Value(result))
-}
+    fn part2(_: &Self::Input) -> Result<SolutionExecution, SolverError> {
+        trace!("Running part 2...");
 
-fn solve_part2(_path: &Path) -> Result<SolutionExecution, String> {
-    trace!("Running part 2...");
-
-    Ok(SolutionExecution::NotImplemented)
+        Ok(SolutionExecution::NotImplemented)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const EXAMPLE_01: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/inputs/y2024/day02_01.example.txt"
+    ));
+
+    #[test]
+    fn test_example_01_p1() {
+        let report = solve(Execute::P1, EXAMPLE_01);
+
+        assert_eq!(report.solutions[0].to_string(), "2");
+    }
+
     mod are_levels_safe {
         use super::*;
 
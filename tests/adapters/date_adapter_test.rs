@@ -1,50 +1,33 @@
 use aoc::{
     providers::date,
-    providers::date::{CurrentDateProvider, Month},
+    providers::date::{CurrentDateProvider, DateAdapter, Month},
 };
-use chrono::Datelike;
+use chrono::{NaiveDate, Utc};
+use chrono_tz::{America::New_York, Asia::Tokyo};
 
 #[test]
-fn test_current_year() {
-    let current = chrono::Local::now().year() as u32;
+fn test_today() {
+    let current = Utc::now().with_timezone(&New_York).date_naive();
     let adapter = date::default_date_provider();
 
-    let result = adapter.current_year();
+    let result = adapter.today();
 
     assert_eq!(result, current);
 }
 
 #[test]
-fn test_current_month() {
-    let current = chrono::Local::now().month();
-    let adapter = date::default_date_provider();
-
-    let current = match current {
-        1 => Month::January,
-        2 => Month::February,
-        3 => Month::March,
-        4 => Month::April,
-        5 => Month::May,
-        6 => Month::June,
-        7 => Month::July,
-        8 => Month::August,
-        9 => Month::September,
-        10 => Month::October,
-        11 => Month::November,
-        12 => Month::December,
-        _ => unreachable!("This should not be reachable"),
-    };
-
-    let result = adapter.current_month();
+fn test_month_of_date() {
+    let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
 
-    assert_eq!(result, current);
+    assert_eq!(Month::of(&date), Month::December);
+    assert_eq!(Month::December.number(), 12);
+    assert_eq!(Month::January.number(), 1);
 }
 
 #[test]
-fn test_current_day() {
-    let current = chrono::Local::now().day();
-    let adapter = date::default_date_provider();
-    let result = adapter.current_day();
+fn test_timezone_override() {
+    let current = Utc::now().with_timezone(&Tokyo).date_naive();
+    let adapter = DateAdapter::new(Tokyo);
 
-    assert_eq!(result, current);
+    assert_eq!(adapter.today(), current);
 }
@@ -1,12 +1,21 @@
+use aoc::constants::DEFAULT_USER_AGENT;
 use aoc::providers::http::{AOCUrl, HTTPAdapter, HTTPError, HTTPProvider};
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
 
 struct URLMock {
     url: String,
+    puzzle: bool,
 }
 
 impl URLMock {
     pub fn new(url: String) -> Self {
-        Self { url }
+        Self { url, puzzle: false }
+    }
+
+    /// Creates the URL of the page or the input of a puzzle
+    pub fn puzzle(url: String) -> Self {
+        Self { url, puzzle: true }
     }
 }
 
@@ -14,6 +23,22 @@ impl AOCUrl for URLMock {
     fn url(&self) -> String {
         self.url.clone()
     }
+
+    fn is_puzzle(&self) -> bool {
+        self.puzzle
+    }
+}
+
+/// Returns an adapter with the test cookie, without throttling and retrying
+/// right away
+fn adapter() -> HTTPAdapter {
+    let mut adapter = HTTPAdapter::default();
+    adapter.set_cookie("TEST-COOKIE".to_string());
+    adapter
+        .set_throttle(Duration::ZERO)
+        .set_retries(2, Duration::ZERO);
+
+    adapter
 }
 
 #[test]
@@ -65,22 +90,245 @@ fn test_fetch_error() {
     let mock = server
         .mock("GET", "/test-endpoint")
         .match_header("Cookie", "session=TEST-COOKIE")
-        .with_status(400)
+        .with_status(403)
         .with_body("FAILED")
         .create();
 
-    let mut adapter = HTTPAdapter::default();
-    adapter.set_cookie("TEST-COOKIE".to_string());
     let url = URLMock::new(server.url() + "/test-endpoint");
-    let result = adapter.get(&url);
+    let result = adapter().get(&url);
 
     mock.assert();
     assert!(result.is_err());
     assert_eq!(
         result.unwrap_err(),
+        HTTPError::FetchError(format!(
+            "HTTP status client error (403 Forbidden) for url ({})",
+            server.url() + "/test-endpoint"
+        ))
+    );
+}
+
+#[test]
+fn test_expired_cookie() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/2024/day/1/input")
+        .with_status(400)
+        .with_body("FAILED")
+        .create();
+
+    let url = URLMock::puzzle(server.url() + "/2024/day/1/input");
+    let result = adapter().get(&url);
+
+    mock.assert();
+    assert_eq!(result.unwrap_err(), HTTPError::ExpiredCookie);
+}
+
+#[test]
+fn test_not_unlocked() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/2024/day/25/input")
+        .with_status(404)
+        .create();
+
+    let url = URLMock::puzzle(server.url() + "/2024/day/25/input");
+    let result = adapter().get(&url);
+
+    mock.assert();
+    assert_eq!(
+        result.unwrap_err(),
+        HTTPError::NotUnlocked(server.url() + "/2024/day/25/input")
+    );
+}
+
+#[test]
+fn test_client_errors_outside_puzzles() {
+    let mut server = mockito::Server::new();
+    let not_found = server
+        .mock("GET", "/2024/leaderboard/private/view/42.json")
+        .with_status(404)
+        .create();
+    let bad_request = server
+        .mock("POST", "/2024/day/1/answer")
+        .with_status(400)
+        .create();
+
+    let leaderboard =
+        URLMock::new(server.url() + "/2024/leaderboard/private/view/42.json");
+    let answer = URLMock::new(server.url() + "/2024/day/1/answer");
+    let adapter = adapter();
+
+    assert_eq!(
+        adapter.get(&leaderboard).unwrap_err(),
+        HTTPError::FetchError(format!(
+            "HTTP status client error (404 Not Found) for url ({})",
+            leaderboard.url()
+        ))
+    );
+    assert_eq!(
+        adapter.post(&answer, &[("level", "1")]).unwrap_err(),
         HTTPError::FetchError(format!(
             "HTTP status client error (400 Bad Request) for url ({})",
+            answer.url()
+        ))
+    );
+    not_found.assert();
+    bad_request.assert();
+}
+
+#[test]
+fn test_user_agent() {
+    let mut server = mockito::Server::new();
+    let default = server
+        .mock("GET", "/default")
+        .match_header("User-Agent", DEFAULT_USER_AGENT)
+        .create();
+    let custom = server
+        .mock("GET", "/custom")
+        .match_header("User-Agent", "aoc-test (me@example.com)")
+        .create();
+
+    let mut adapter = adapter();
+    adapter
+        .get(&URLMock::new(server.url() + "/default"))
+        .unwrap();
+    adapter.set_user_agent("aoc-test (me@example.com)".to_string());
+    adapter
+        .get(&URLMock::new(server.url() + "/custom"))
+        .unwrap();
+
+    default.assert();
+    custom.assert();
+}
+
+#[test]
+fn test_server_errors_are_retried() {
+    let mut server = mockito::Server::new();
+    // The first attempt and the 2 retries
+    let failing = server
+        .mock("GET", "/test-endpoint")
+        .with_status(502)
+        .expect(3)
+        .create();
+
+    let url = URLMock::new(server.url() + "/test-endpoint");
+    let result = adapter().get(&url);
+
+    failing.assert();
+    assert_eq!(
+        result.unwrap_err(),
+        HTTPError::FetchError(format!(
+            "HTTP status server error (502 Bad Gateway) for url ({})",
             server.url() + "/test-endpoint"
         ))
     );
 }
+
+#[test]
+fn test_connection_errors_are_retried() {
+    // Nothing listens on the port once the listener is dropped
+    let url = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        URLMock::new(format!("http://{}/test-endpoint", address))
+    };
+    let mut adapter = adapter();
+    adapter.set_retries(2, Duration::from_millis(50));
+
+    let start = Instant::now();
+    let result = adapter.get(&url);
+
+    assert!(matches!(result, Err(HTTPError::FetchError(_))));
+    // Waits 50ms before the first retry and 100ms before the second one
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[test]
+fn test_posts_are_not_retried_after_server_errors() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/test-endpoint")
+        .with_status(500)
+        .expect(1)
+        .create();
+
+    let url = URLMock::new(server.url() + "/test-endpoint");
+    let result = adapter().post(&url, &[("level", "1"), ("answer", "42")]);
+
+    mock.assert();
+    assert!(matches!(result, Err(HTTPError::FetchError(_))));
+}
+
+#[test]
+fn test_posts_are_retried_after_connection_errors() {
+    // Nothing listens on the port once the listener is dropped
+    let url = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        URLMock::new(format!("http://{}/test-endpoint", address))
+    };
+    let mut adapter = adapter();
+    adapter.set_retries(1, Duration::from_millis(50));
+
+    let start = Instant::now();
+    let result = adapter.post(&url, &[("level", "1"), ("answer", "42")]);
+
+    assert!(matches!(result, Err(HTTPError::FetchError(_))));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn test_requests_are_throttled() {
+    let mut server = mockito::Server::new();
+    let mock = server.mock("GET", "/test-endpoint").expect(2).create();
+    let url = URLMock::new(server.url() + "/test-endpoint");
+
+    let mut adapter = adapter();
+    adapter.set_throttle(Duration::from_millis(200));
+
+    let start = Instant::now();
+    adapter.get(&url).unwrap();
+    adapter.get(&url).unwrap();
+
+    mock.assert();
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[test]
+fn test_post_endpoint() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/test-endpoint")
+        .match_header("Cookie", "session=TEST-COOKIE")
+        .match_header("Content-Type", "application/x-www-form-urlencoded")
+        .match_body("level=1&answer=42")
+        .with_status(200)
+        .with_body("TEST-SUCCESS")
+        .create();
+
+    let mut adapter = HTTPAdapter::default();
+    adapter.set_cookie("TEST-COOKIE".to_string());
+
+    let url = URLMock::new(server.url() + "/test-endpoint");
+    let result = adapter.post(&url, &[("level", "1"), ("answer", "42")]);
+
+    mock.assert();
+    assert_eq!(result.unwrap(), "TEST-SUCCESS");
+}
+
+#[test]
+fn test_post_needs_cookie() {
+    let mut server = mockito::Server::new();
+    let mock = server.mock("POST", "/test-endpoint").create();
+
+    let adapter = HTTPAdapter::default();
+    let url = URLMock::new(server.url() + "/test-endpoint");
+    let result = adapter.post(&url, &[("level", "1"), ("answer", "42")]);
+
+    assert!(!mock.matched());
+    assert_eq!(
+        result.unwrap_err(),
+        HTTPError::MissingEnvVarError("AOC_COOKIE not set".into())
+    );
+}
@@ -0,0 +1,123 @@
+use crate::fixtures;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+
+fn auth(config: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    cmd.arg("auth")
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config);
+
+    cmd
+}
+
+#[test]
+fn test_set_and_clear() {
+    let config_dir = tempfile::tempdir().unwrap();
+    let config = config_dir.path().join("aoc").join("config.toml");
+
+    auth(&config)
+        .arg("set")
+        .arg("session=53616c74")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Session cookie saved to"));
+
+    assert_eq!(
+        std::fs::read_to_string(&config).unwrap(),
+        "session = \"53616c74\"\n"
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = std::fs::metadata(&config).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    auth(&config)
+        .arg("clear")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Session cookie removed from"));
+
+    assert_eq!(std::fs::read_to_string(&config).unwrap(), "");
+
+    auth(&config)
+        .arg("clear")
+        .assert()
+        .success()
+        .stdout("No session cookie stored\n");
+}
+
+#[test]
+fn test_set_from_stdin() {
+    let config_dir = tempfile::tempdir().unwrap();
+    let config = config_dir.path().join("config.toml");
+
+    auth(&config)
+        .arg("set")
+        .write_stdin("53616c74\n")
+        .assert()
+        .success();
+
+    assert_eq!(
+        std::fs::read_to_string(&config).unwrap(),
+        "session = \"53616c74\"\n"
+    );
+}
+
+#[test]
+fn test_set_empty_cookie() {
+    let config_dir = tempfile::tempdir().unwrap();
+    let config = config_dir.path().join("config.toml");
+
+    auth(&config)
+        .arg("set")
+        .write_stdin("\n")
+        .assert()
+        .code(exitcode::DATAERR)
+        .stderr("The session cookie is empty\n");
+
+    assert!(!config.exists());
+}
+
+#[test]
+fn test_check_without_cookie() {
+    let config_dir = tempfile::tempdir().unwrap();
+
+    auth(&config_dir.path().join("config.toml"))
+        .arg("check")
+        .assert()
+        .code(exitcode::CONFIG)
+        .stderr(predicate::str::contains("No AoC session cookie found."));
+}
+
+#[test]
+fn test_stored_cookie_is_used() {
+    let config_dir = tempfile::tempdir().unwrap();
+    let config = config_dir.path().join("config.toml");
+    std::fs::write(&config, "session = \"53616c74\"\n").unwrap();
+
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let cached = inputs_dir.path().join("y2024").join("leaderboard_42.json");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::copy(fixtures::get_data_path("leaderboard.json"), cached).unwrap();
+
+    // The leaderboard is cached, but the command still requires a cookie
+    Command::cargo_bin("aoc")
+        .unwrap()
+        .arg("leaderboard")
+        .arg("42")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", &config)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Alice"));
+}
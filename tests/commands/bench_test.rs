@@ -0,0 +1,99 @@
+use crate::fixtures;
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_bench_puzzle() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("bench")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--runs")
+        .arg("3")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .env("AOC_INPUTS_DIR", fixtures::get_data_path(""))
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("Benchmark 2024/01, 3 runs\n"))
+        .stdout(
+            predicate::str::is_match(
+                r"\nParse: min .+, median .+, mean .+ ± .+\n",
+            )
+            .unwrap(),
+        )
+        .stdout(predicate::str::contains("\nP1: 11, min "))
+        .stdout(predicate::str::contains("\nP2: 31, min "));
+}
+
+#[test]
+fn test_bench_csv() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    let output = cmd
+        .arg("bench")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("2")
+        .arg("p1")
+        .arg("--runs")
+        .arg("2")
+        .arg("--warmup")
+        .arg("0")
+        .arg("--style")
+        .arg("csv")
+        .env("AOC_INPUTS_DIR", fixtures::get_data_path(""))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success());
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].starts_with("2024,2,parse,,2,"));
+    assert!(lines[2].starts_with("2024,2,p1,2,2,"));
+    assert_eq!(lines[3], "2024,2,p2,Not executed,2,,,,");
+}
+
+#[test]
+fn test_bench_all_reports_missing_inputs() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("bench")
+        .arg("--all")
+        .arg("--year")
+        .arg("2024")
+        .arg("--style")
+        .arg("json")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .assert()
+        .code(exitcode::IOERR)
+        .stdout(predicate::str::contains(
+            r#""kind":"bench","year":2024,"day":1,"runs":0"#,
+        ))
+        .stdout(predicate::str::contains(r#""year":2024,"day":2,"runs":0"#));
+}
+
+#[test]
+fn test_bench_without_cached_input() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("bench")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .assert()
+        .code(exitcode::NOINPUT)
+        .stderr(predicate::str::contains("No input found at"));
+}
@@ -1,17 +1,36 @@
+use aoc::services::DateService;
+use aoc::{calendar, Puzzle};
 use assert_cmd::Command;
 use predicates::prelude::predicate;
 
+/// Returns the last puzzle of the latest event, unless it is already
+/// unlocked
+fn locked_puzzle() -> Option<Puzzle> {
+    let service = DateService::default();
+    let year = service.latest_year();
+    let puzzle = Puzzle::new(year, calendar::last_day(year)).unwrap();
+
+    service.time_until_unlock(&puzzle).map(|_| puzzle)
+}
+
 #[test]
 fn test_aoc_env_missing() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
 
     cmd.arg("download")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
         .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
         .assert()
-        .failure()
-        .stderr(predicate::str::contains(
-            "error: the following required arguments were not provided:",
-        ));
+        .code(exitcode::CONFIG)
+        .stderr(predicate::str::contains("No AoC session cookie found."));
 }
 
 #[test]
@@ -43,3 +62,96 @@ fn test_download_happy_path() -> Result<(), &'static str> {
 
     Ok(())
 }
+
+#[test]
+fn test_cached_input_is_not_downloaded() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let cached = inputs_dir.path().join("y2024").join("day01.txt");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(&cached, "3   4\n").unwrap();
+
+    cmd.arg("download")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .success()
+        .stdout("3   4\n");
+}
+
+#[test]
+fn test_cached_input_needs_no_cookie() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let cached = inputs_dir.path().join("y2024").join("day01.txt");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(&cached, "3   4\n").unwrap();
+
+    cmd.arg("download")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
+        .assert()
+        .success()
+        .stdout("3   4\n");
+}
+
+#[test]
+fn test_wait_for_unlocked_puzzle() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let cached = inputs_dir.path().join("y2024").join("day01.txt");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(&cached, "3   4\n").unwrap();
+
+    // The puzzle unlocked long ago, so there is nothing to wait for
+    cmd.arg("download")
+        .arg("--wait")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .timeout(std::time::Duration::from_secs(10))
+        .assert()
+        .success()
+        .stdout("3   4\n");
+}
+
+#[test]
+fn test_locked_puzzle_is_refused_without_wait() {
+    // From the last unlock until the new year, every puzzle is out
+    let Some(puzzle) = locked_puzzle() else {
+        eprintln!("Skipping, every puzzle of the latest event is unlocked");
+        return;
+    };
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("download")
+        .arg("--year")
+        .arg(puzzle.year().to_string())
+        .arg("--day")
+        .arg(puzzle.day().to_string())
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .env_remove("AOC_TIMEZONE")
+        .assert()
+        .code(exitcode::UNAVAILABLE)
+        .stderr(predicate::str::contains("is not unlocked yet"))
+        .stderr(predicate::str::contains("Use --wait"));
+}
@@ -0,0 +1,88 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The total distance is <code><em>11</em></code>.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The similarity score is <code><em>31</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+#[test]
+fn test_examples_from_page() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let page = inputs_dir.path().join("page.html");
+    std::fs::write(&page, PAGE).unwrap();
+    let example = inputs_dir.path().join("y2024").join("day01_01.example.txt");
+
+    cmd.arg("examples")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--html")
+        .arg(&page)
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env_remove("AOC_COOKIE")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Example saved to"))
+        .stdout(predicate::str::contains("const EXAMPLE_01: &str"))
+        .stdout(predicate::str::contains(
+            "fn test_example_01_p1() {\n        \
+             let report = solve(Execute::P1, EXAMPLE_01);\n\n        \
+             assert_eq!(report.solutions[0].to_string(), \"11\");",
+        ))
+        .stdout(predicate::str::contains(
+            "assert_eq!(report.solutions[1].to_string(), \"31\");",
+        ));
+
+    assert_eq!(std::fs::read_to_string(example).unwrap(), "3   4\n4   3\n");
+}
+
+#[test]
+fn test_examples_without_answers() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let page = inputs_dir.path().join("page.html");
+    std::fs::write(
+        &page,
+        r#"<article class="day-desc"><pre><code>1</code></pre></article>"#,
+    )
+    .unwrap();
+
+    cmd.arg("examples")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--html")
+        .arg(&page)
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .assert()
+        .code(exitcode::DATAERR)
+        .stderr(predicate::str::contains("No answer found for the examples"));
+}
+
+#[test]
+fn test_missing_page() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("examples")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--html")
+        .arg("this/file/does/not/exist.html")
+        .assert()
+        .code(exitcode::NOINPUT);
+}
@@ -1,11 +1,12 @@
 use assert_cmd::Command;
-use chrono::{Datelike, Local};
+use chrono::{Datelike, Utc};
+use chrono_tz::America::New_York;
 use predicates::prelude::*;
 
 #[test]
 fn test_help() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
-    let current = Local::now();
+    let current = Utc::now().with_timezone(&New_York);
     let current_year = current.year();
     let current_month = current.month();
     let current_day = current.day();
@@ -17,7 +18,8 @@ fn test_help() {
         expected_year = current_year - 1;
     }
 
-    if current_month != 12 || current_day > 25 {
+    let last_day = if current_year >= 2025 { 12 } else { 25 };
+    if current_month != 12 || current_day > last_day {
         expected_day = 1;
     }
 
@@ -27,20 +29,38 @@ fn test_help() {
 Usage: aoc [OPTIONS] <COMMAND>
 
 Commands:
-  download  Downloads the specified puzzle input from AoC
-  solve     Solve the specified puzzle
-  generate  Generate the boilerplate code to solve the aforementioned challenge
-  help      Print this message or the help of the given subcommand(s)
+  download        Downloads the specified puzzle input from AoC
+  read            Shows the description of the specified puzzle, and saves its examples
+  solve           Solve the specified puzzle
+  bench           Benchmark the specified puzzle with its cached input, reporting the time spent parsing and solving each part over many runs
+  submit          Submit the answer of a part of the specified puzzle to AoC
+  verify          Check every implemented puzzle against its confirmed answers
+  progress        Shows the stars earned every year, from the confirmed answers
+  leaderboard     Shows a private leaderboard of the selected year
+  examples        Saves the examples of the specified puzzle, and prints the tests checking their answers
+  auth            Manages the AoC session cookie stored in the user configuration
+  generate        Generate the boilerplate code to solve the aforementioned challenge. Tests for the examples are added when the description is cached
+  migrate-inputs  Rename the cached inputs to the yYYYY/dayDD.txt naming scheme
+  help            Print this message or the help of the given subcommand(s)
 
 Options:
-  -y, --year <YEAR>  Selected year. Defaults to current year on December, last year otherwise [default: {}]
-  -d, --day <DAY>    Selected day. Defaults to current day on December between 1-25, 1 otherwise [default: {}]
-  -h, --help         Print help
-  -V, --version      Print version"#,
+  -y, --year <YEAR>                Selected year. Defaults to current year on December, last year otherwise. Dates are in AoC's timezone, America/New_York, unless AOC_TIMEZONE is set [default: {}]
+  -d, --day <DAY>                  Selected day. Defaults to current day on December during the event, 1 otherwise [default: {}]
+      --inputs-dir <INPUTS_DIR>    Directory where the puzzle inputs are cached [env: AOC_INPUTS_DIR=] [default: inputs]
+      --answers-dir <ANSWERS_DIR>  Directory where the submitted answers are recorded [env: AOC_ANSWERS_DIR=] [default: answers]
+      --user-agent <USER_AGENT>    User-Agent sent to AoC. Defaults to the name of the crate and its author [env: AOC_USER_AGENT=]
+      --config <CONFIG>            User configuration, where `aoc auth set` stores the session cookie. Defaults to aoc/config.toml in the user config directory [env: AOC_CONFIG=]
+  -h, --help                       Print help
+  -V, --version                    Print version"#,
         expected_year, expected_day
     );
 
     cmd.arg("help")
+        .env_remove("AOC_INPUTS_DIR")
+        .env_remove("AOC_ANSWERS_DIR")
+        .env_remove("AOC_USER_AGENT")
+        .env_remove("AOC_CONFIG")
+        .env_remove("AOC_TIMEZONE")
         .assert()
         .success()
         .stdout(predicate::str::contains(expected));
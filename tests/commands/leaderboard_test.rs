@@ -0,0 +1,114 @@
+use crate::fixtures;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// Returns an inputs directory with the leaderboard fixture cached as the
+/// leaderboard 42 of 2024
+fn cached_leaderboard() -> TempDir {
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let cached = inputs_dir.path().join("y2024").join("leaderboard_42.json");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::copy(fixtures::get_data_path("leaderboard.json"), cached).unwrap();
+
+    inputs_dir
+}
+
+#[test]
+fn test_aoc_env_missing() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("leaderboard")
+        .arg("42")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
+        .assert()
+        .code(exitcode::CONFIG)
+        .stderr(predicate::str::contains("No AoC session cookie found."));
+}
+
+#[test]
+fn test_cached_rankings() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = cached_leaderboard();
+
+    cmd.arg("leaderboard")
+        .arg("42")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "│ 1 │ Alice               │    14 │     4 │",
+        ))
+        .stdout(predicate::str::contains(
+            "│ 2 │ (anonymous user #2) │     7 │     2 │",
+        ));
+}
+
+#[test]
+fn test_cached_leaderboard_needs_no_cookie() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    let inputs_dir = cached_leaderboard();
+
+    cmd.arg("leaderboard")
+        .arg("42")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Alice"));
+}
+
+#[test]
+fn test_cached_timeline() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = cached_leaderboard();
+
+    cmd.arg("leaderboard")
+        .arg("42")
+        .arg("--year")
+        .arg("2024")
+        .arg("--view")
+        .arg("timeline")
+        .arg("--no-color")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "│ 1   │ P1   │ (anonymous user #2) │      0:03:20 │",
+        ));
+}
+
+#[test]
+fn test_invalid_view() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("leaderboard")
+        .arg("42")
+        .arg("--view")
+        .arg("global")
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "[possible values: rankings, local-score, delta-time, timeline]",
+        ));
+}
@@ -0,0 +1,33 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+#[test]
+fn test_migrate_inputs() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let year_dir = inputs_dir.path().join("y2024");
+
+    fs::create_dir(&year_dir).unwrap();
+    fs::write(year_dir.join("01.txt"), "INPUT").unwrap();
+    fs::write(year_dir.join("01.example.txt"), "EXAMPLE").unwrap();
+    fs::write(year_dir.join("day02.txt"), "CANONICAL").unwrap();
+
+    cmd.arg("migrate-inputs")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 file(s) renamed"));
+
+    assert_eq!(
+        fs::read_to_string(year_dir.join("day01.txt")).unwrap(),
+        "INPUT"
+    );
+    assert_eq!(
+        fs::read_to_string(year_dir.join("day01_01.example.txt")).unwrap(),
+        "EXAMPLE"
+    );
+    assert!(year_dir.join("day02.txt").is_file());
+    assert!(!year_dir.join("01.txt").exists());
+}
@@ -1,3 +1,12 @@
+mod auth_test;
+mod bench_test;
 mod download_test;
+mod examples_test;
 mod help_test;
+mod leaderboard_test;
+mod migrate_inputs_test;
+mod progress_test;
+mod read_test;
 mod solve_test;
+mod submit_test;
+mod verify_test;
@@ -0,0 +1,89 @@
+use crate::fixtures;
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_calendar() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("progress")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("      1  2  3"))
+        .stdout(predicate::str::contains("\n2024 ** *     "))
+        .stdout(predicate::str::ends_with(" 3/50\n"))
+        .stdout(predicate::str::contains("2023").not());
+}
+
+#[test]
+fn test_update_readme() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let readme = dir.path().join("README.md");
+    std::fs::write(
+        &readme,
+        "# AoC\n\n<!-- aoc-progress:start -->\n<!-- aoc-progress:end -->\n",
+    )
+    .unwrap();
+
+    cmd.arg("progress")
+        .arg("--year")
+        .arg("2024")
+        .arg("--readme")
+        .arg(&readme)
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Progress updated in"));
+
+    let updated = std::fs::read_to_string(&readme).unwrap();
+    assert!(updated.starts_with("# AoC\n\n<!-- aoc-progress:start -->\n"));
+    assert!(updated.contains("| 2024 | ⭐⭐ | ⭐ |  |"));
+    assert!(updated.contains("**Total: 3/50 stars**"));
+    assert!(updated.ends_with("<!-- aoc-progress:end -->\n"));
+}
+
+#[test]
+fn test_readme_without_section() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let readme = dir.path().join("README.md");
+    std::fs::write(&readme, "# AoC\n").unwrap();
+
+    cmd.arg("progress")
+        .arg("--readme")
+        .arg(&readme)
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .code(exitcode::DATAERR)
+        .stderr(predicate::str::contains("has no progress section"));
+
+    assert_eq!(std::fs::read_to_string(&readme).unwrap(), "# AoC\n");
+}
+
+#[test]
+fn test_update_repository_readme() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let readme = dir.path().join("README.md");
+    std::fs::copy(
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md"),
+        &readme,
+    )
+    .unwrap();
+
+    cmd.arg("progress")
+        .arg("--readme")
+        .arg(&readme)
+        .env("AOC_ANSWERS_DIR", dir.path().join("answers"))
+        .assert()
+        .success();
+
+    // Without any confirmed answer, the implemented days are still shown
+    let updated = std::fs::read_to_string(&readme).unwrap();
+    assert!(updated.contains("| 2024 | . | . |  |"));
+}
@@ -0,0 +1,131 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::Path;
+
+const DESCRIPTION: &str = r#"<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The total distance is <code><em>11</em></code>.</p>
+</article>"#;
+
+/// Caches the description of 2024/01 in `inputs_dir`
+fn cache_description(inputs_dir: &Path) {
+    let cached = inputs_dir.join("y2024").join("day01.html");
+    std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+    std::fs::write(cached, DESCRIPTION).unwrap();
+}
+
+#[test]
+fn test_aoc_env_missing() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("read")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
+        .assert()
+        .code(exitcode::CONFIG)
+        .stderr(predicate::str::contains("No AoC session cookie found."));
+}
+
+#[test]
+fn test_cached_description_as_text() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    cache_description(inputs_dir.path());
+
+    cmd.arg("read")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .success()
+        .stdout(
+            "--- Day 1: Historian Hysteria ---\n\nFor example:\n\n    \
+             3   4\n    4   3\n\nThe total distance is 11.\n",
+        );
+}
+
+#[test]
+fn test_cached_description_needs_no_cookie() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    cache_description(inputs_dir.path());
+
+    cmd.arg("read")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "--- Day 1: Historian Hysteria ---",
+        ));
+}
+
+#[test]
+fn test_cached_description_as_markdown() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    cache_description(inputs_dir.path());
+
+    cmd.arg("read")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--part")
+        .arg("1")
+        .arg("--format")
+        .arg("markdown")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "## Day 1: Historian Hysteria\n",
+        ))
+        .stdout(predicate::str::contains("```\n3   4\n4   3\n```"))
+        .stdout(predicate::str::contains("The total distance is **`11`**."));
+}
+
+#[test]
+fn test_examples_are_saved() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    cache_description(inputs_dir.path());
+    let example = inputs_dir.path().join("y2024").join("day01_01.example.txt");
+
+    cmd.arg("read")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "wrong")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Example saved to"));
+
+    assert_eq!(std::fs::read_to_string(example).unwrap(), "3   4\n4   3\n");
+}
@@ -1,52 +1,148 @@
 use crate::fixtures;
 use assert_cmd::Command;
+use chrono::{Datelike, Utc};
+use chrono_tz::America::New_York;
 use predicates::prelude::*;
 
 #[test]
-fn test_without_parameters() {
+fn test_without_cached_input() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
 
     cmd.arg("solve")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
         .assert()
         .failure()
-        .stderr(predicate::str::contains(
-            "error: the following required arguments were not provided:
-  <PUZZLE_INPUT>
+        .stderr(predicate::str::contains("No input found at"));
+}
 
-Usage: aoc solve <PUZZLE_INPUT> [EXECUTE]
+#[test]
+fn test_default_cached_input() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = fixtures::get_data_path("");
 
-For more information, try '--help'.",
-        ));
+    cmd.arg("solve")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .env("AOC_INPUTS_DIR", inputs_dir)
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Solutions 2024/01\n11 ✔\n31 ✔"));
 }
 
 #[test]
-fn test_help() {
+fn test_regression_is_marked() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let answers_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        answers_dir.path().join("y2024.toml"),
+        "[day01.p1]\nconfirmed = \"12\"\n",
+    )
+    .unwrap();
 
     cmd.arg("solve")
-  .arg("--help")
-  .assert()
-  .success()
-  .stdout(predicate::str::contains("Solve the specified puzzle
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .arg("--answers-dir")
+        .arg(answers_dir.path())
+        .env("AOC_INPUTS_DIR", fixtures::get_data_path(""))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Solutions 2024/01\n11 ✘\n31 ?"));
+}
 
-Usage: aoc solve [OPTIONS] <PUZZLE_INPUT> [EXECUTE]
+#[test]
+fn test_explicit_input_is_not_marked() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
+
+    cmd.arg("solve")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .arg(data_path)
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Solutions 2024/01\n11\n31\n"));
+}
+
+#[test]
+fn test_help() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let current = Utc::now().with_timezone(&New_York);
+
+    let mut expected_year = current.year();
+    let mut expected_day = current.day();
+
+    if current.month() != 12 {
+        expected_year -= 1;
+    }
+
+    let last_day = if current.year() >= 2025 { 12 } else { 25 };
+    if current.month() != 12 || expected_day > last_day {
+        expected_day = 1;
+    }
+
+    let expected = format!("Solve the specified puzzle
+
+Usage: aoc solve [OPTIONS] [PUZZLE_INPUT] [EXECUTE]
 
 Arguments:
-  <PUZZLE_INPUT>  
+  [PUZZLE_INPUT]  Path to the puzzle input, or `-` to read it from stdin. Defaults to the cached input of the puzzle
   [EXECUTE]       [default: all] [possible values: all, p1, p2]
 
 Options:
-  -s, --style <STYLE>  Control how the results are displayed [default: tabulated] [possible values: simple, tabulated]
-  -y, --year <YEAR>    Selected year. Defaults to current year on December, last year otherwise [default: 2024]
-  -d, --day <DAY>      Selected day. Defaults to current day on December between 1-25, 1 otherwise [default: 1]
-      --no-color       Removes the color from the ouput [default: false]
-  -h, --help           Print help"));
+  -s, --style <STYLE>              Control how the results are displayed [default: tabulated] [possible values: simple, tabulated, json, markdown, csv, junit]
+  -y, --year <YEAR>                Selected year. Defaults to current year on December, last year otherwise. Dates are in AoC's timezone, America/New_York, unless AOC_TIMEZONE is set [default: {}]
+  -d, --day <DAY>                  Selected day. Defaults to current day on December during the event, 1 otherwise [default: {}]
+      --no-color                   Removes the color from the ouput [default: false]
+      --inputs-dir <INPUTS_DIR>    Directory where the puzzle inputs are cached [env: AOC_INPUTS_DIR=] [default: inputs]
+      --timeout <TIMEOUT>          Gives up on the puzzle after this many seconds
+      --all                        Solves every implemented puzzle with its cached input. Only the puzzles of the selected year when --year is given
+      --answers-dir <ANSWERS_DIR>  Directory where the submitted answers are recorded [env: AOC_ANSWERS_DIR=] [default: answers]
+  -j, --jobs <JOBS>                Number of puzzles solved at the same time with --all. Defaults to the number of available cores
+      --user-agent <USER_AGENT>    User-Agent sent to AoC. Defaults to the name of the crate and its author [env: AOC_USER_AGENT=]
+      --config <CONFIG>            User configuration, where `aoc auth set` stores the session cookie. Defaults to aoc/config.toml in the user config directory [env: AOC_CONFIG=]
+      --split-parts                Solves part 1 and part 2 of each puzzle in parallel with --all
+  -h, --help                       Print help", expected_year, expected_day);
+
+    cmd.arg("solve")
+        .arg("--help")
+        .env_remove("AOC_INPUTS_DIR")
+        .env_remove("AOC_ANSWERS_DIR")
+        .env_remove("AOC_USER_AGENT")
+        .env_remove("AOC_CONFIG")
+        .env_remove("AOC_TIMEZONE")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(expected));
 }
 
 #[test]
 fn test_happy_path() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
-    let data_path = fixtures::get_data_path("y2024/01.txt");
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
 
     cmd.arg("solve")
         .arg("--year")
@@ -62,10 +158,82 @@ fn test_happy_path() {
         .stdout(predicate::str::contains("Time elapsed:"));
 }
 
+#[test]
+fn test_json_style() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
+
+    let output = cmd
+        .arg("solve")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--style")
+        .arg("json")
+        .arg(data_path)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    // The output is a single JSON document, nothing else
+    let document: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(document["schema"], 1);
+    assert_eq!(document["kind"], "solve");
+    assert_eq!(document["year"], 2024);
+    assert_eq!(document["day"], 1);
+    assert_eq!(document["parts"][0]["status"], "solved");
+    assert!(document["parts"][1]["elapsed_ns"].is_u64());
+}
+
+#[test]
+fn test_input_from_stdin() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
+
+    cmd.arg("solve")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .arg("-")
+        .pipe_stdin(data_path)
+        .unwrap()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Solutions 2024/01\n11\n31"));
+}
+
+#[test]
+fn test_parse_error_exit_code() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("solve")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .arg("-")
+        .write_stdin("3   4\n4   x\n")
+        .assert()
+        .code(exitcode::DATAERR)
+        .stdout(predicate::str::contains(
+            "Error: parse error at line 2, column 5: Invalid number\n2 | 4   x\n  |     ^",
+        ));
+}
+
 #[test]
 fn test_invalid_execute_option() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
-    let data_path = fixtures::get_data_path("y2024/01.txt");
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
 
     cmd.arg("solve")
         .arg("--year")
@@ -85,7 +253,7 @@ fn test_invalid_execute_option() {
 #[test]
 fn test_p1_execution_only() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
-    let data_path = fixtures::get_data_path("y2024/01.txt");
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
 
     cmd.arg("solve")
         .arg("--year")
@@ -106,7 +274,7 @@ fn test_p1_execution_only() {
 #[test]
 fn test_p2_execution_only() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
-    let data_path = fixtures::get_data_path("y2024/01.txt");
+    let data_path = fixtures::get_data_path("y2024/day01.txt");
 
     cmd.arg("solve")
         .arg("--year")
@@ -123,3 +291,103 @@ fn test_p2_execution_only() {
         .stdout(predicate::str::contains("P2"))
         .stdout(predicate::str::contains("Time elapsed:"));
 }
+
+#[test]
+fn test_all_puzzles_of_a_year() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = fixtures::get_data_path("");
+
+    cmd.arg("solve")
+        .arg("--all")
+        .arg("--year")
+        .arg("2024")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .env("AOC_INPUTS_DIR", inputs_dir)
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Solutions 2024/01\n11 ✔\n31 ✔"))
+        .stdout(predicate::str::contains("Solutions 2024/02\n2 ✔\n"))
+        .stdout(predicate::str::contains("Total:"))
+        .stdout(predicate::str::contains("2023").not());
+}
+
+#[test]
+fn test_all_puzzles_keeps_going_on_failure() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+    let day01 = inputs_dir.path().join("y2024").join("day01.txt");
+    std::fs::create_dir_all(day01.parent().unwrap()).unwrap();
+    std::fs::copy(fixtures::get_data_path("y2024/day01.txt"), day01).unwrap();
+
+    cmd.arg("solve")
+        .arg("--all")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .assert()
+        .code(exitcode::IOERR)
+        .stdout(predicate::str::contains("│ 2024/01 │ 11 "))
+        .stdout(predicate::str::contains("│ 2024/02 │ Error: I/O error"))
+        .stdout(predicate::str::contains("│ Total "));
+}
+
+#[test]
+fn test_all_puzzles_in_parallel() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = fixtures::get_data_path("");
+
+    cmd.arg("solve")
+        .arg("--all")
+        .arg("--year")
+        .arg("2024")
+        .arg("--jobs")
+        .arg("2")
+        .arg("--split-parts")
+        .arg("--style")
+        .arg("simple")
+        .arg("--no-color")
+        .env("AOC_INPUTS_DIR", inputs_dir)
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::is_match(
+                "Solutions 2024/01\n11 ✔\n31 ✔\n(.|\n)*Solutions 2024/02\n2 ✔\n",
+            )
+            .unwrap(),
+        );
+}
+
+#[test]
+fn test_jobs_requires_all() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("solve")
+        .arg("--jobs")
+        .arg("2")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
+
+#[test]
+fn test_all_conflicts_with_day() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("solve")
+        .arg("--all")
+        .arg("--day")
+        .arg("1")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--all' cannot be used with '--day <DAY>'",
+        ));
+}
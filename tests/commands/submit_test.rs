@@ -0,0 +1,126 @@
+use crate::fixtures;
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_aoc_env_missing() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let config_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("submit")
+        .arg("--answer")
+        .arg("42")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .env_remove("AOC_COOKIE")
+        .env("AOC_CONFIG", config_dir.path().join("config.toml"))
+        .env("AOC_ANSWERS_DIR", config_dir.path())
+        .assert()
+        .code(exitcode::CONFIG)
+        .stderr(predicate::str::contains("No AoC session cookie found."));
+}
+
+#[test]
+fn test_invalid_part() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("submit")
+        .arg("all")
+        .env("AOC_COOKIE", "TEST-COOKIE")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("[possible values: p1, p2]"));
+}
+
+#[test]
+fn test_part_not_implemented() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = fixtures::get_data_path("");
+
+    cmd.arg("submit")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("2")
+        .arg("p2")
+        .env("AOC_COOKIE", "TEST-COOKIE")
+        .env("AOC_INPUTS_DIR", inputs_dir)
+        .assert()
+        .code(exitcode::UNAVAILABLE)
+        .stderr(predicate::str::contains("P2 is not implemented!"));
+}
+
+#[test]
+fn test_without_cached_input() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let inputs_dir = tempfile::tempdir().unwrap();
+
+    cmd.arg("submit")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("1")
+        .arg("--inputs-dir")
+        .arg(inputs_dir.path())
+        .env("AOC_COOKIE", "TEST-COOKIE")
+        .assert()
+        .code(exitcode::NOINPUT)
+        .stderr(predicate::str::contains("No input found at"));
+}
+
+#[test]
+fn test_known_wrong_answer_is_not_sent() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let answers_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        answers_dir.path().join("y2024.toml"),
+        "[day03.p1]\nwrong = [\"7\"]\n",
+    )
+    .unwrap();
+
+    cmd.arg("submit")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("3")
+        .arg("--answer")
+        .arg("7")
+        .arg("--answers-dir")
+        .arg(answers_dir.path())
+        .env("AOC_COOKIE", "TEST-COOKIE")
+        .assert()
+        .code(exitcode::DATAERR)
+        .stderr(predicate::str::contains(
+            "Not submitting 7: this answer was already rejected",
+        ));
+}
+
+#[test]
+fn test_out_of_bounds_answer_is_not_sent() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let answers_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        answers_dir.path().join("y2024.toml"),
+        "[day03.p2]\ntoo_low = [\"100\"]\n",
+    )
+    .unwrap();
+
+    cmd.arg("submit")
+        .arg("p2")
+        .arg("--year")
+        .arg("2024")
+        .arg("--day")
+        .arg("3")
+        .arg("--answer")
+        .arg("50")
+        .arg("--answers-dir")
+        .arg(answers_dir.path())
+        .env("AOC_COOKIE", "TEST-COOKIE")
+        .assert()
+        .code(exitcode::DATAERR)
+        .stderr(predicate::str::contains(
+            "this answer is too low, it should be higher than 100",
+        ));
+}
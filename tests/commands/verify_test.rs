@@ -0,0 +1,47 @@
+use crate::fixtures;
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_confirmed_answers_pass() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    cmd.arg("verify")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .env("AOC_INPUTS_DIR", fixtures::get_data_path(""))
+        .env("AOC_ANSWERS_DIR", fixtures::get_data_path("answers"))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("│ 2024/01 │ 11 ✔ "))
+        .stdout(predicate::str::contains(
+            "Verified: 3 passed, 0 failed, 0 without a confirmed answer",
+        ));
+}
+
+#[test]
+fn test_mismatch_fails() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+    let answers_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        answers_dir.path().join("y2024.toml"),
+        "[day01.p1]\nconfirmed = \"11\"\n\n[day01.p2]\nconfirmed = \"30\"\n",
+    )
+    .unwrap();
+
+    cmd.arg("verify")
+        .arg("--year")
+        .arg("2024")
+        .arg("--no-color")
+        .arg("--answers-dir")
+        .arg(answers_dir.path())
+        .env("AOC_INPUTS_DIR", fixtures::get_data_path(""))
+        .assert()
+        .code(exitcode::DATAERR)
+        .stdout(predicate::str::contains("│ 2024/01 │ 11 ✔ "))
+        .stdout(predicate::str::contains("│ 31 ✘ "))
+        .stdout(predicate::str::contains(
+            "Verified: 1 passed, 1 failed, 1 without a confirmed answer",
+        ));
+}
@@ -1,15 +1,18 @@
-use aoc::constants::{VALID_DAYS, VALID_YEARS};
+use aoc::calendar;
+use aoc::services::DateService;
 use assert_cmd::Command;
 use predicates::prelude::*;
 
 #[test]
 fn invalid_year_test() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
+    // The binary takes the latest year from the same date service
+    let years = calendar::years(DateService::default().latest_year());
 
     let expected_error = format!(
         "Error: InvalidYear(\"year should be in [{}-{}] range. Current: 1900.\")",
-        VALID_YEARS.start(),
-        VALID_YEARS.end()
+        years.start(),
+        years.end()
     );
 
     cmd.arg("--year")
@@ -26,13 +29,12 @@ fn invalid_year_test() {
 fn invalid_day_test() {
     let mut cmd = Command::cargo_bin("aoc").unwrap();
 
-    let expected_error = format!(
-        "Error: InvalidDay(\"day should be in [{}-{}] range. Current: 26.\")",
-        VALID_DAYS.start(),
-        VALID_DAYS.end()
-    );
+    let expected_error =
+        "Error: InvalidDay(\"day should be in [1-25] range. Current: 26.\")";
 
-    cmd.arg("--day")
+    cmd.arg("--year")
+        .arg("2024")
+        .arg("--day")
         .arg("26") // First invalid day after the valid range
         .arg("download")
         .arg("--aoc-cookie")
@@ -41,3 +43,22 @@ fn invalid_day_test() {
         .failure()
         .stderr(predicate::str::contains(expected_error));
 }
+
+#[test]
+fn invalid_day_since_2025_test() {
+    let mut cmd = Command::cargo_bin("aoc").unwrap();
+
+    let expected_error =
+        "Error: InvalidDay(\"day should be in [1-12] range. Current: 13.\")";
+
+    cmd.arg("--year")
+        .arg("2025")
+        .arg("--day")
+        .arg("13")
+        .arg("download")
+        .arg("--aoc-cookie")
+        .arg("dummy-cookie")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(expected_error));
+}
@@ -0,0 +1,57 @@
+//! Checks the solvers against the confirmed answers of `answers/`, and the
+//! answers of the examples of `answers/examples/`.
+//!
+//! The tests are generated by `build.rs`, one per answer. The ones using
+//! the real input are ignored when it is not available, like in checkouts
+//! where `inputs/` is still encrypted.
+
+use aoc::services::fs::{example_path, input_path};
+use aoc::solvers::{self, Solution};
+use aoc::{Part, Puzzle};
+use std::path::Path;
+
+/// Solves the part with the cached input and checks the answer
+// Unused until a first answer is confirmed
+#[allow(dead_code)]
+fn verify(year: u32, day: u32, part: Part, expected: &str) {
+    let puzzle = Puzzle::new(year, day).unwrap();
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    let path = input_path(&inputs_dir, &puzzle);
+
+    let input = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+    check(&puzzle, part, &input, expected);
+}
+
+/// Solves the part with the given example and checks the answer
+fn verify_example(
+    year: u32,
+    day: u32,
+    part: Part,
+    example: usize,
+    expected: &str,
+) {
+    let puzzle = Puzzle::new(year, day).unwrap();
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    let path = example_path(&inputs_dir, &puzzle, example);
+    let input = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+    check(&puzzle, part, &input, expected);
+}
+
+/// Solves the part of the puzzle with `input` and checks the answer
+fn check(puzzle: &Puzzle, part: Part, input: &str, expected: &str) {
+    let solver = solvers::get_solver(puzzle).unwrap_or_else(|| {
+        panic!("{}/{:02} has no solver", puzzle.year(), puzzle.day())
+    });
+    let report = solver(part.execute(), input);
+
+    match &report.solutions[part.level() as usize - 1] {
+        Solution::Value(answer) => assert_eq!(answer.to_string(), expected),
+        solution => panic!("Expected {}, got {:?}", expected, solution),
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/verify_tests.rs"));